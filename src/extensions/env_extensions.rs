@@ -1,15 +1,58 @@
 #![allow(non_upper_case_globals)]
-use soroban_sdk::storage::{Instance, Persistent};
-use soroban_sdk::{panic_with_error, Address, Env};
+use soroban_sdk::storage::{Instance, Persistent, Temporary};
+use soroban_sdk::{panic_with_error, Address, BytesN, Env, IntoVal, Map, TryFromVal, Val, Vec};
 
 use crate::types;
 
-use types::{error::Error, ballot::Ballot, ballot_category::BallotCategory};
+use types::{
+    asset::Asset, ballot::Ballot, ballot_category::BallotCategory, emission_schedule::EmissionSchedule,
+    error::Error, node_info::NodeInfo, pause_group::PauseGroup, queued_change::QueuedChange, role::Role, signer_set::SignerSet,
+    price_feed::PriceFeed, revenue_shares::RevenueShares, vesting_schedule::VestingSchedule,
+    voting_rules::VotingRules,
+};
 const ADMIN_KEY: &str = "admin";
 const LAST_BALLOT_ID: &str = "last_ballot_id";
 const LAST_UNLOCK: &str = "last_unlock";
+const EPOCH: &str = "epoch";
+const EMISSION_SCHEDULE: &str = "emission_schedule";
+const OPERATORS: &str = "operators";
+const DEVELOPERS: &str = "developers";
+const KEEPER_REWARD: &str = "keeper_reward";
 const TOKEN_KEY: &str = "token";
 const DAO_BALANCE: &str = "dao_balance";
+const VOTING_RULES: &str = "voting_rules";
+const NODES: &str = "nodes";
+const NODE_INDEX: &str = "node_index";
+const ASSETS: &str = "assets";
+const ASSET_INDEX: &str = "asset_index";
+const FEEDS: &str = "feeds";
+const FEED_INDEX: &str = "feed_index";
+const ORACLE: &str = "oracle";
+const REVENUE_BALANCE: &str = "revenue_balance";
+const REVENUE_ACCRUED: &str = "revenue_accrued";
+const REVENUE_SHARES: &str = "revenue_shares";
+const DONATIONS: &str = "donations";
+const LIABILITIES: &str = "liabilities";
+const ESCROWED: &str = "escrowed";
+const VESTING: &str = "vesting";
+const VESTING_RESERVED: &str = "vesting_reserved";
+const PAYOUT_ADDRESS: &str = "payout_address";
+const CLAIM_OPERATOR: &str = "claim_operator";
+const PUSH_MODE: &str = "push_mode";
+const ROLES: &str = "roles";
+const SIGNERS: &str = "signers";
+const APPROVALS: &str = "approvals";
+const TIMELOCK_DELAY: &str = "timelock_delay";
+const LAST_CHANGE_ID: &str = "last_change_id";
+const QUEUED_CHANGES: &str = "queued_changes";
+const PAUSED: &str = "paused";
+
+// 6 months, per-account entries live in persistent storage to keep the instance storage bounded
+const ACCOUNT_ENTRY_TTL: u32 = 17280 * 30 * 6;
+// 30 days, approvals of abandoned actions expire instead of piling up
+const APPROVAL_TTL: u32 = 17280 * 30;
+// 6 months, registry entries are extended on every update
+const REGISTRY_ENTRY_TTL: u32 = 17280 * 30 * 6;
 
 pub trait EnvExtensions {
     fn get_admin(&self) -> Option<Address>;
@@ -18,6 +61,10 @@ pub trait EnvExtensions {
 
     fn get_token(&self) -> Address;
 
+    fn get_oracle(&self) -> Option<Address>;
+
+    fn set_oracle(&self, oracle: &Address);
+
     fn set_token(&self, token: &Address);
 
     fn get_last_ballot_id(&self) -> u64;
@@ -28,6 +75,26 @@ pub trait EnvExtensions {
 
     fn get_last_unlock(&self) -> u64;
 
+    fn get_epoch(&self) -> u32;
+
+    fn set_epoch(&self, epoch: u32);
+
+    fn get_emission_schedule(&self) -> EmissionSchedule;
+
+    fn set_emission_schedule(&self, schedule: &EmissionSchedule);
+
+    fn get_operators(&self) -> Vec<Address>;
+
+    fn set_operators(&self, operators: &Vec<Address>);
+
+    fn get_developers(&self) -> Map<Address, u32>;
+
+    fn set_developers(&self, developers: &Map<Address, u32>);
+
+    fn get_keeper_reward(&self) -> i128;
+
+    fn set_keeper_reward(&self, amount: i128);
+
     fn get_ballot(&self, ballot_id: u64) -> Option<Ballot>;
 
     fn set_ballot(&self, ballot_id: u64, ballot: &Ballot);
@@ -36,103 +103,524 @@ pub trait EnvExtensions {
 
     fn get_deposit(&self, ballot_category: BallotCategory) -> i128;
 
+    fn set_voting_rules(&self, ballot_category: BallotCategory, rules: &VotingRules);
+
+    fn get_voting_rules(&self, ballot_category: BallotCategory) -> VotingRules;
+
     fn get_dao_balance(&self) -> i128;
 
     fn set_dao_balance(&self, balance: i128);
 
+    fn get_node(&self, pubkey: &BytesN<32>) -> Option<NodeInfo>;
+
+    fn set_node(&self, node: &NodeInfo);
+
+    fn remove_node(&self, pubkey: &BytesN<32>);
+
+    fn get_nodes(&self) -> Vec<NodeInfo>;
+
+    fn has_asset(&self, asset: &Asset) -> bool;
+
+    fn add_asset(&self, asset: &Asset);
+
+    fn remove_asset(&self, asset: &Asset);
+
+    fn get_assets(&self, offset: u32, limit: u32) -> Vec<Asset>;
+
+    fn get_feed(&self, feed_id: u64) -> Option<PriceFeed>;
+
+    fn set_feed(&self, feed: &PriceFeed);
+
+    fn get_feeds(&self) -> Vec<PriceFeed>;
+
+    fn get_revenue_balance(&self) -> i128;
+
+    fn set_revenue_balance(&self, balance: i128);
+
+    fn get_revenue_accrued(&self) -> i128;
+
+    fn set_revenue_accrued(&self, amount: i128);
+
+    fn get_revenue_shares(&self) -> RevenueShares;
+
+    fn set_revenue_shares(&self, shares: &RevenueShares);
+
+    fn get_donations(&self, donor: &Address) -> i128;
+
+    fn set_donations(&self, donor: &Address, amount: i128);
+
+    fn get_liabilities(&self) -> i128;
+
+    fn set_liabilities(&self, amount: i128);
+
+    fn get_escrowed(&self) -> i128;
+
+    fn set_escrowed(&self, amount: i128);
+
+    fn get_vesting(&self, beneficiary: &Address) -> Option<VestingSchedule>;
+
+    fn set_vesting(&self, beneficiary: &Address, schedule: &VestingSchedule);
+
+    fn remove_vesting(&self, beneficiary: &Address);
+
+    fn get_vesting_reserved(&self) -> i128;
+
+    fn set_vesting_reserved(&self, amount: i128);
+
+    fn get_payout_address(&self, claimant: &Address) -> Option<Address>;
+
+    fn set_payout_address(&self, claimant: &Address, payout: &Address);
+
+    fn remove_payout_address(&self, claimant: &Address);
+
+    fn get_claim_operator(&self, claimant: &Address) -> Option<Address>;
+
+    fn set_claim_operator(&self, claimant: &Address, operator: &Address);
+
+    fn remove_claim_operator(&self, claimant: &Address);
+
+    fn get_push_mode(&self, recipient: &Address) -> bool;
+
+    fn set_push_mode(&self, recipient: &Address, enabled: bool);
+
+    fn get_role(&self, role: Role) -> Option<Address>;
+
+    fn set_role(&self, role: Role, address: &Address);
+
+    fn remove_role(&self, role: Role);
+
+    fn get_signers(&self) -> Option<SignerSet>;
+
+    fn set_signers(&self, signers: &SignerSet);
+
+    fn get_approvals(&self, action: &BytesN<32>) -> Vec<Address>;
+
+    fn set_approvals(&self, action: &BytesN<32>, approvals: &Vec<Address>);
+
+    fn remove_approvals(&self, action: &BytesN<32>);
+
+    fn get_timelock_delay(&self) -> u64;
+
+    fn set_timelock_delay(&self, delay: u64);
+
+    fn get_last_change_id(&self) -> u64;
+
+    fn set_last_change_id(&self, last_change_id: u64);
+
+    fn get_queued_changes(&self) -> Map<u64, QueuedChange>;
+
+    fn set_queued_changes(&self, changes: &Map<u64, QueuedChange>);
+
+    fn is_paused(&self, group: PauseGroup) -> bool;
+
+    fn set_paused(&self, group: PauseGroup, paused: bool);
+
     fn get_available_balance(&self, address: &Address) -> i128;
 
     fn set_available_balance(&self, address: &Address, balance: i128);
 
     fn extend_ballot_ttl(&self, ballot_id: u64, extend_to: u32);
 
-    fn panic_if_not_admin(&self);
+    fn panic_if_not_admin(&self, action: &BytesN<32>);
+
+    fn panic_if_not_role(&self, role: Role, action: &BytesN<32>);
+
+    fn panic_if_paused(&self, group: PauseGroup);
 
     fn is_initialized(&self) -> bool;
 }
 
 impl EnvExtensions for Env {
     fn is_initialized(&self) -> bool {
-        get_instance_storage(&self).has(&ADMIN_KEY)
+        get_instance_storage(self).has(&ADMIN_KEY)
     }
 
     fn get_admin(&self) -> Option<Address> {
-        get_instance_storage(&self).get(&ADMIN_KEY)
+        get_instance_storage(self).get(&ADMIN_KEY)
     }
 
     fn set_admin(&self, admin: &Address) {
-        get_instance_storage(&self).set(&ADMIN_KEY, admin);
+        get_instance_storage(self).set(&ADMIN_KEY, admin);
     }
 
     fn get_token(&self) -> Address {
-        get_instance_storage(&self).get(&TOKEN_KEY).unwrap()
+        get_instance_storage(self).get(&TOKEN_KEY).unwrap()
     }
 
     fn set_token(&self, token: &Address) {
-        get_instance_storage(&self).set(&TOKEN_KEY, token);
+        get_instance_storage(self).set(&TOKEN_KEY, token);
+    }
+
+    fn get_oracle(&self) -> Option<Address> {
+        get_instance_storage(self).get(&ORACLE)
+    }
+
+    fn set_oracle(&self, oracle: &Address) {
+        get_instance_storage(self).set(&ORACLE, oracle);
     }
 
     fn get_dao_balance(&self) -> i128 {
-        get_instance_storage(&self).get(&DAO_BALANCE).unwrap_or(0)
+        get_instance_storage(self).get(&DAO_BALANCE).unwrap_or(0)
     }
 
     fn set_dao_balance(&self, balance: i128) {
-        get_instance_storage(&self).set(&DAO_BALANCE, &balance);
+        get_instance_storage(self).set(&DAO_BALANCE, &balance);
+    }
+
+    fn get_node(&self, pubkey: &BytesN<32>) -> Option<NodeInfo> {
+        get_registry_entry(self, NODES, pubkey)
+    }
+
+    fn set_node(&self, node: &NodeInfo) {
+        set_registry_entry(self, NODES, NODE_INDEX, &node.pubkey, node);
+    }
+
+    fn remove_node(&self, pubkey: &BytesN<32>) {
+        remove_registry_entry(self, NODES, NODE_INDEX, pubkey);
+    }
+
+    fn get_nodes(&self) -> Vec<NodeInfo> {
+        get_registry_entries(self, NODES, NODE_INDEX, 0, u32::MAX)
+    }
+
+    fn has_asset(&self, asset: &Asset) -> bool {
+        get_registry_entry::<Asset>(self, ASSETS, asset).is_some()
+    }
+
+    fn add_asset(&self, asset: &Asset) {
+        set_registry_entry(self, ASSETS, ASSET_INDEX, asset, asset);
+    }
+
+    fn remove_asset(&self, asset: &Asset) {
+        remove_registry_entry(self, ASSETS, ASSET_INDEX, asset);
+    }
+
+    fn get_assets(&self, offset: u32, limit: u32) -> Vec<Asset> {
+        get_registry_entries(self, ASSETS, ASSET_INDEX, offset, limit)
+    }
+
+    fn get_feed(&self, feed_id: u64) -> Option<PriceFeed> {
+        get_registry_entry(self, FEEDS, &feed_id)
+    }
+
+    fn set_feed(&self, feed: &PriceFeed) {
+        set_registry_entry(self, FEEDS, FEED_INDEX, &feed.id, feed);
+    }
+
+    fn get_feeds(&self) -> Vec<PriceFeed> {
+        get_registry_entries(self, FEEDS, FEED_INDEX, 0, u32::MAX)
+    }
+
+    fn get_revenue_balance(&self) -> i128 {
+        get_instance_storage(self).get(&REVENUE_BALANCE).unwrap_or(0)
+    }
+
+    fn set_revenue_balance(&self, balance: i128) {
+        get_instance_storage(self).set(&REVENUE_BALANCE, &balance);
+    }
+
+    fn get_revenue_accrued(&self) -> i128 {
+        get_instance_storage(self).get(&REVENUE_ACCRUED).unwrap_or(0)
+    }
+
+    fn set_revenue_accrued(&self, amount: i128) {
+        get_instance_storage(self).set(&REVENUE_ACCRUED, &amount);
+    }
+
+    fn get_revenue_shares(&self) -> RevenueShares {
+        get_instance_storage(self).get(&REVENUE_SHARES).unwrap()
+    }
+
+    fn set_revenue_shares(&self, shares: &RevenueShares) {
+        get_instance_storage(self).set(&REVENUE_SHARES, shares);
+    }
+
+    fn get_donations(&self, donor: &Address) -> i128 {
+        get_persistent_storage(self).get(&(DONATIONS, donor.clone())).unwrap_or(0)
+    }
+
+    fn set_donations(&self, donor: &Address, amount: i128) {
+        set_account_entry(self, &(DONATIONS, donor.clone()), &amount);
+    }
+
+    fn get_liabilities(&self) -> i128 {
+        get_instance_storage(self).get(&LIABILITIES).unwrap_or(0)
+    }
+
+    fn set_liabilities(&self, amount: i128) {
+        get_instance_storage(self).set(&LIABILITIES, &amount);
+    }
+
+    fn get_escrowed(&self) -> i128 {
+        get_instance_storage(self).get(&ESCROWED).unwrap_or(0)
+    }
+
+    fn set_escrowed(&self, amount: i128) {
+        get_instance_storage(self).set(&ESCROWED, &amount);
+    }
+
+    fn get_vesting(&self, beneficiary: &Address) -> Option<VestingSchedule> {
+        get_persistent_storage(self).get(&(VESTING, beneficiary.clone()))
+    }
+
+    fn set_vesting(&self, beneficiary: &Address, schedule: &VestingSchedule) {
+        set_account_entry(self, &(VESTING, beneficiary.clone()), schedule);
+    }
+
+    fn remove_vesting(&self, beneficiary: &Address) {
+        get_persistent_storage(self).remove(&(VESTING, beneficiary.clone()));
+    }
+
+    fn get_vesting_reserved(&self) -> i128 {
+        get_instance_storage(self).get(&VESTING_RESERVED).unwrap_or(0)
+    }
+
+    fn set_vesting_reserved(&self, amount: i128) {
+        get_instance_storage(self).set(&VESTING_RESERVED, &amount);
+    }
+
+    fn get_payout_address(&self, claimant: &Address) -> Option<Address> {
+        get_persistent_storage(self).get(&(PAYOUT_ADDRESS, claimant.clone()))
+    }
+
+    fn set_payout_address(&self, claimant: &Address, payout: &Address) {
+        set_account_entry(self, &(PAYOUT_ADDRESS, claimant.clone()), payout);
+    }
+
+    fn remove_payout_address(&self, claimant: &Address) {
+        get_persistent_storage(self).remove(&(PAYOUT_ADDRESS, claimant.clone()));
+    }
+
+    fn get_claim_operator(&self, claimant: &Address) -> Option<Address> {
+        get_persistent_storage(self).get(&(CLAIM_OPERATOR, claimant.clone()))
+    }
+
+    fn set_claim_operator(&self, claimant: &Address, operator: &Address) {
+        set_account_entry(self, &(CLAIM_OPERATOR, claimant.clone()), operator);
+    }
+
+    fn remove_claim_operator(&self, claimant: &Address) {
+        get_persistent_storage(self).remove(&(CLAIM_OPERATOR, claimant.clone()));
+    }
+
+    fn get_push_mode(&self, recipient: &Address) -> bool {
+        get_persistent_storage(self).get(&(PUSH_MODE, recipient.clone())).unwrap_or(false)
+    }
+
+    fn set_push_mode(&self, recipient: &Address, enabled: bool) {
+        if enabled {
+            set_account_entry(self, &(PUSH_MODE, recipient.clone()), &true);
+        } else {
+            get_persistent_storage(self).remove(&(PUSH_MODE, recipient.clone()));
+        }
+    }
+
+    fn get_role(&self, role: Role) -> Option<Address> {
+        get_instance_storage(self).get(&(ROLES, role))
+    }
+
+    fn set_role(&self, role: Role, address: &Address) {
+        get_instance_storage(self).set(&(ROLES, role), address);
+    }
+
+    fn remove_role(&self, role: Role) {
+        get_instance_storage(self).remove(&(ROLES, role));
+    }
+
+    fn get_signers(&self) -> Option<SignerSet> {
+        get_instance_storage(self).get(&SIGNERS)
+    }
+
+    fn set_signers(&self, signers: &SignerSet) {
+        get_instance_storage(self).set(&SIGNERS, signers);
+    }
+
+    fn get_approvals(&self, action: &BytesN<32>) -> Vec<Address> {
+        get_temporary_storage(self).get(&(APPROVALS, action.clone())).unwrap_or(Vec::new(self))
+    }
+
+    fn set_approvals(&self, action: &BytesN<32>, approvals: &Vec<Address>) {
+        let key = (APPROVALS, action.clone());
+        let storage = get_temporary_storage(self);
+        storage.set(&key, approvals);
+        storage.extend_ttl(&key, APPROVAL_TTL, APPROVAL_TTL);
+    }
+
+    fn remove_approvals(&self, action: &BytesN<32>) {
+        get_temporary_storage(self).remove(&(APPROVALS, action.clone()));
+    }
+
+    fn get_timelock_delay(&self) -> u64 {
+        get_instance_storage(self).get(&TIMELOCK_DELAY).unwrap_or(0)
+    }
+
+    fn set_timelock_delay(&self, delay: u64) {
+        get_instance_storage(self).set(&TIMELOCK_DELAY, &delay);
+    }
+
+    fn get_last_change_id(&self) -> u64 {
+        get_instance_storage(self).get(&LAST_CHANGE_ID).unwrap_or(0)
+    }
+
+    fn set_last_change_id(&self, last_change_id: u64) {
+        get_instance_storage(self).set(&LAST_CHANGE_ID, &last_change_id);
+    }
+
+    fn get_queued_changes(&self) -> Map<u64, QueuedChange> {
+        get_instance_storage(self).get(&QUEUED_CHANGES).unwrap_or(Map::new(self))
+    }
+
+    fn set_queued_changes(&self, changes: &Map<u64, QueuedChange>) {
+        get_instance_storage(self).set(&QUEUED_CHANGES, changes);
+    }
+
+    fn is_paused(&self, group: PauseGroup) -> bool {
+        get_instance_storage(self).get(&(PAUSED, group)).unwrap_or(false)
+    }
+
+    fn set_paused(&self, group: PauseGroup, paused: bool) {
+        if paused {
+            get_instance_storage(self).set(&(PAUSED, group), &true);
+        } else {
+            get_instance_storage(self).remove(&(PAUSED, group));
+        }
     }
 
     fn get_available_balance(&self, address: &Address) -> i128 {
-        get_instance_storage(&self).get(&address.to_string()).unwrap_or(0)
+        get_instance_storage(self).get(&address.to_string()).unwrap_or(0)
     }
 
     fn set_available_balance(&self, address: &Address, balance: i128) {
-        get_instance_storage(&self).set(&address.to_string(), &balance);
+        get_instance_storage(self).set(&address.to_string(), &balance);
     }
 
     fn get_last_ballot_id(&self) -> u64 {
-        get_instance_storage(&self)
+        get_instance_storage(self)
             .get(&LAST_BALLOT_ID)
             .unwrap_or(0)
     }
 
     fn set_last_ballot_id(&self, last_ballot_id: u64) {
-        get_instance_storage(&self).set(&LAST_BALLOT_ID, &last_ballot_id);
+        get_instance_storage(self).set(&LAST_BALLOT_ID, &last_ballot_id);
     }
 
     fn get_ballot(&self, ballot_id: u64) -> Option<Ballot> {
-        get_persistent_storage(&self).get(&ballot_id)
+        get_persistent_storage(self).get(&ballot_id)
     }
 
     fn set_ballot(&self, ballot_id: u64, ballot: &Ballot) {
-        get_persistent_storage(&self).set(&ballot_id, ballot);
+        get_persistent_storage(self).set(&ballot_id, ballot);
     }
 
     fn set_deposit(&self, ballot_category: BallotCategory, amount: i128) {
-        get_instance_storage(&self).set(&ballot_category, &amount);
+        get_instance_storage(self).set(&ballot_category, &amount);
     }
 
     fn get_deposit(&self, ballot_category: BallotCategory) -> i128 {
-        get_instance_storage(&self).get(&ballot_category).unwrap()
+        get_instance_storage(self).get(&ballot_category).unwrap()
+    }
+
+    fn set_voting_rules(&self, ballot_category: BallotCategory, rules: &VotingRules) {
+        get_instance_storage(self).set(&(VOTING_RULES, ballot_category), rules);
+    }
+
+    fn get_voting_rules(&self, ballot_category: BallotCategory) -> VotingRules {
+        get_instance_storage(self).get(&(VOTING_RULES, ballot_category)).unwrap()
     }
 
     fn get_last_unlock(&self) -> u64 {
-        get_instance_storage(&self).get(&LAST_UNLOCK).unwrap_or(0)
+        get_instance_storage(self).get(&LAST_UNLOCK).unwrap_or(0)
     }
 
     fn set_last_unlock(&self, last_uplock: u64) {
-        get_instance_storage(&self).set(&LAST_UNLOCK, &last_uplock);
+        get_instance_storage(self).set(&LAST_UNLOCK, &last_uplock);
+    }
+
+    fn get_epoch(&self) -> u32 {
+        get_instance_storage(self).get(&EPOCH).unwrap_or(0)
+    }
+
+    fn set_epoch(&self, epoch: u32) {
+        get_instance_storage(self).set(&EPOCH, &epoch);
+    }
+
+    fn get_emission_schedule(&self) -> EmissionSchedule {
+        get_instance_storage(self).get(&EMISSION_SCHEDULE).unwrap()
+    }
+
+    fn set_emission_schedule(&self, schedule: &EmissionSchedule) {
+        get_instance_storage(self).set(&EMISSION_SCHEDULE, schedule);
+    }
+
+    fn get_operators(&self) -> Vec<Address> {
+        get_instance_storage(self).get(&OPERATORS).unwrap_or(Vec::new(self))
+    }
+
+    fn set_operators(&self, operators: &Vec<Address>) {
+        get_instance_storage(self).set(&OPERATORS, operators);
+    }
+
+    fn get_developers(&self) -> Map<Address, u32> {
+        get_instance_storage(self).get(&DEVELOPERS).unwrap_or(Map::new(self))
+    }
+
+    fn set_developers(&self, developers: &Map<Address, u32>) {
+        get_instance_storage(self).set(&DEVELOPERS, developers);
+    }
+
+    fn get_keeper_reward(&self) -> i128 {
+        get_instance_storage(self).get(&KEEPER_REWARD).unwrap_or(0)
+    }
+
+    fn set_keeper_reward(&self, amount: i128) {
+        get_instance_storage(self).set(&KEEPER_REWARD, &amount);
     }
 
     fn extend_ballot_ttl(&self, ballot_id: u64, extend_to: u32) {
-        get_persistent_storage(&self).extend_ttl(&ballot_id, extend_to, extend_to)
+        get_persistent_storage(self).extend_ttl(&ballot_id, extend_to, extend_to)
     }
 
-    fn panic_if_not_admin(&self) {
+    fn panic_if_not_admin(&self, action: &BytesN<32>) {
+        // the signer set replaces the admin once configured
+        if let Some(signer_set) = self.get_signers() {
+            // count approvals of the current signers only
+            let approvals = self.get_approvals(action);
+            let approved = approvals.iter().filter(|signer| signer_set.signers.contains(signer)).count() as u32;
+            if approved < signer_set.threshold {
+                panic_with_error!(self, Error::Unauthorized);
+            }
+            // approvals can be used only once
+            self.remove_approvals(action);
+            return;
+        }
         let admin = self.get_admin();
         if admin.is_none() {
             panic_with_error!(self, Error::Unauthorized);
         }
         admin.unwrap().require_auth()
     }
+
+    fn panic_if_not_role(&self, role: Role, action: &BytesN<32>) {
+        // ballot decisions and parameter changes stay under the signer threshold once the signer set is configured
+        if matches!(role, Role::Governor | Role::VoteRecorder) && self.get_signers().is_some() {
+            if let Some(address) = self.get_role(role) {
+                address.require_auth();
+            }
+            self.panic_if_not_admin(action);
+            return;
+        }
+        match self.get_role(role) {
+            Some(address) => address.require_auth(),
+            // unassigned roles fall back to the admin
+            None => self.panic_if_not_admin(action),
+        }
+    }
+
+    fn panic_if_paused(&self, group: PauseGroup) {
+        if self.is_paused(group) {
+            panic_with_error!(self, Error::Paused);
+        }
+    }
 }
 
 fn get_instance_storage(e: &Env) -> Instance {
@@ -142,3 +630,81 @@ fn get_instance_storage(e: &Env) -> Instance {
 fn get_persistent_storage(e: &Env) -> Persistent {
     e.storage().persistent()
 }
+
+fn get_temporary_storage(e: &Env) -> Temporary {
+    e.storage().temporary()
+}
+
+// save the per-account entry in persistent storage and extend its TTL
+fn set_account_entry<K: IntoVal<Env, Val>, V: IntoVal<Env, Val>>(e: &Env, key: &K, value: &V) {
+    let storage = get_persistent_storage(e);
+    storage.set(key, value);
+    storage.extend_ttl(key, ACCOUNT_ENTRY_TTL, ACCOUNT_ENTRY_TTL);
+}
+
+// registry entries are stored per key in persistent storage along with their position in the registry index,
+// the index maps positions to keys for enumeration and only the entry count is kept in instance storage
+fn get_registry_entry<V: TryFromVal<Env, Val>>(e: &Env, registry: &str, key: &impl IntoVal<Env, Val>) -> Option<V> {
+    let key: Val = key.into_val(e);
+    get_persistent_storage(e)
+        .get::<_, (u32, Val)>(&(registry, key))
+        .map(|(_, value)| value.into_val(e))
+}
+
+fn set_registry_entry(e: &Env, registry: &str, index: &str, key: &impl IntoVal<Env, Val>, value: &impl IntoVal<Env, Val>) {
+    let storage = get_persistent_storage(e);
+    let key: Val = key.into_val(e);
+    let entry_key = (registry, key);
+    let position = match storage.get::<_, (u32, Val)>(&entry_key) {
+        Some((position, _)) => position,
+        None => {
+            // append the key to the index
+            let count = get_registry_count(e, registry);
+            let index_key = (index, count);
+            storage.set(&index_key, &key);
+            storage.extend_ttl(&index_key, REGISTRY_ENTRY_TTL, REGISTRY_ENTRY_TTL);
+            get_instance_storage(e).set(&registry, &(count + 1));
+            count
+        }
+    };
+    let value: Val = value.into_val(e);
+    storage.set(&entry_key, &(position, value));
+    storage.extend_ttl(&entry_key, REGISTRY_ENTRY_TTL, REGISTRY_ENTRY_TTL);
+}
+
+fn remove_registry_entry(e: &Env, registry: &str, index: &str, key: &impl IntoVal<Env, Val>) {
+    let storage = get_persistent_storage(e);
+    let key: Val = key.into_val(e);
+    let entry_key = (registry, key);
+    let position = match storage.get::<_, (u32, Val)>(&entry_key) {
+        Some((position, _)) => position,
+        None => return,
+    };
+    let last = get_registry_count(e, registry) - 1;
+    // move the last key into the vacated position to keep the index dense
+    if position != last {
+        let last_key: Val = storage.get(&(index, last)).unwrap();
+        let (_, last_value): (u32, Val) = storage.get(&(registry, last_key)).unwrap();
+        storage.set(&(index, position), &last_key);
+        storage.set(&(registry, last_key), &(position, last_value));
+    }
+    storage.remove(&(index, last));
+    storage.remove(&entry_key);
+    get_instance_storage(e).set(&registry, &last);
+}
+
+fn get_registry_entries<V: IntoVal<Env, Val> + TryFromVal<Env, Val>>(e: &Env, registry: &str, index: &str, offset: u32, limit: u32) -> Vec<V> {
+    let storage = get_persistent_storage(e);
+    let count = get_registry_count(e, registry);
+    let mut entries = Vec::new(e);
+    for position in offset.min(count)..offset.saturating_add(limit).min(count) {
+        let key: Val = storage.get(&(index, position)).unwrap();
+        let (_, value): (u32, Val) = storage.get(&(registry, key)).unwrap();
+        entries.push_back(value.into_val(e));
+    }
+    entries
+}
+
+fn get_registry_count(e: &Env, registry: &str) -> u32 {
+    get_instance_storage(e).get(&registry).unwrap_or(0)
+}
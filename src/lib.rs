@@ -1,21 +1,31 @@
 #![no_std]
 use extensions::env_extensions::EnvExtensions;
-use soroban_sdk::{contract, contractimpl, symbol_short, token::TokenClient, Address, Env, Map, Symbol, Vec};
+use oracle::OracleClient;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, token::TokenClient, xdr::ToXdr, Address, BytesN, Env, IntoVal, Map, Symbol, Val,
+    Vec,
+};
 use types::{
-    ballot::Ballot, ballot_category::BallotCategory, ballot_init_params::BallotInitParams,
-    ballot_status::BallotStatus, contract_config::ContractConfig, error::Error,
+    asset::Asset, ballot::Ballot, ballot_category::BallotCategory, ballot_init_params::BallotInitParams,
+    ballot_payload::BallotPayload, ballot_status::BallotStatus, contract_config::ContractConfig,
+    emission_schedule::EmissionSchedule, error::Error, execution_status::ExecutionStatus, node_info::NodeInfo,
+    param_change::ParamChange, pause_group::PauseGroup, price_feed::PriceFeed, queued_change::QueuedChange,
+    revenue_shares::RevenueShares, role::Role, signer_set::SignerSet, solvency_report::SolvencyReport,
+    vesting_params::VestingParams, vesting_schedule::VestingSchedule, vote_rationale::VoteRationale,
+    voting_rules::VotingRules,
 };
 
 mod extensions;
+mod oracle;
 mod types;
 
 //10000 is 100%
 const PERCENTAGE_FACTOR: i128 = 10000;
 
-// 0.12% weekly distribution, 10000 is 100%
+// operators receive 12 of every 15 emitted tokens (0.12% weekly distribution with 0.15% decay emission)
 const OPERATORS_SHARE: i128 = 12;
 
-// 0.03% weekly distribution, 10000 is 100%
+// developers receive 3 of every 15 emitted tokens (0.03% weekly distribution with 0.15% decay emission)
 const DEVELOPERS_SHARE: i128 = 3;
 
 // 1 week
@@ -27,6 +37,12 @@ const BALLOT_DURATION: u32 = 604800 * 2;
 // 2 months
 const BALLOT_RENTAL_PERIOD: u32 = 17280 * 30 * 2;
 
+// ~10 years of weekly unlocks
+const MAX_PREVIEW_EPOCHS: u32 = 520;
+
+// 30 days
+const MAX_TIMELOCK_DELAY: u64 = 86400 * 30;
+
 const REFLECTOR: Symbol = symbol_short!("reflector");
 
 #[contract]
@@ -46,6 +62,9 @@ impl DAOContract {
     /// Panics if the contract has been already initialized
     /// Panics if the deposit amounts is invalid
     /// Panics if the deposit amount is not set for all categories
+    /// Panics if the voting rules are invalid or not set for all categories
+    /// Panics if the emission schedule is invalid
+    /// Panics if the revenue shares are invalid
     pub fn config(e: Env, config: ContractConfig) {
         // check admin permissions
         config.admin.require_auth();
@@ -61,16 +80,26 @@ impl DAOContract {
         e.set_admin(&config.admin);
         e.set_token(&config.token);
         e.set_last_unlock(config.start_date);
+        if let Some(oracle) = &config.oracle {
+            e.set_oracle(oracle);
+        }
         // transfer tokens to the DAO contract
         token(&e).transfer(&config.admin, &e.current_contract_address(), &config.amount);
         // set initial DAO balance
-        update_dao_balance(&e, config.amount.into());
+        update_dao_balance(&e, config.amount);
         //set deposit params
         set_deposit(&e, config.deposit_params);
+        //set voting rules
+        set_voting_rules(&e, config.voting_rules);
+        //set emission schedule
+        set_emission_schedule(&e, config.emission_schedule);
+        //set revenue shares
+        set_revenue_shares(&e, config.revenue_shares);
     }
 
     /// Sets the deposit amount for each ballot category
-    /// Requires admin permissions
+    /// The change is queued if the timelock delay is set, see `execute_change`
+    /// Requires governor permissions
     ///
     /// # Arguments
     ///
@@ -78,314 +107,1573 @@ impl DAOContract {
     ///
     /// # Panics
     ///
-    /// Panics if the caller doesn't match admin address
+    /// Panics if the caller doesn't match governor address
     /// Panics if the deposit amount is invalid
     /// Panics if the deposit amount is not set for all categories
     pub fn set_deposit(e: Env, deposit_params: Map<BallotCategory, i128>) {
-        e.panic_if_not_admin();
-        set_deposit(&e, deposit_params);
+        e.panic_if_not_role(Role::Governor, &action_hash(&e, "set_deposit", (deposit_params.clone(),).into_val(&e)));
+        schedule_change(&e, ParamChange::Deposit(deposit_params));
     }
 
-    /// Unlocks tokens distributed to the developer organization and operators on a weekly basis
-    /// Requires admin permissions
+    /// Sets the quorum and approval threshold for each ballot category
+    /// The change is queued if the timelock delay is set, see `execute_change`
+    /// Requires governor permissions
     ///
     /// # Arguments
     ///
-    /// * `developer` - Developer organization account address
-    /// * `operators` - Operators' account addresses
+    /// * `voting_rules` - Map of voting rules for each ballot category
     ///
     /// # Panics
     ///
-    /// Panics if the caller doesn't match admin address
-    /// Panics if the unlock process has been initiated too early
-    pub fn unlock(e: Env, developer: Address, operators: Vec<Address>) {
-        // check admin permissions
-        e.panic_if_not_admin();
-        // retrieve last recorded unlock period timestamp
-        let last_unlock = e.get_last_unlock();
-        let now = e.ledger().timestamp();
-        // panic if the unlock routine has been initiated too early
-        if now - last_unlock < UNLOCK_PERIOD as u64 {
-            e.panic_with_error(Error::UnlockUnavailable);
-        }
-        // check if the operators list is empty or not unique
-        if operators.is_empty() ||
-            operators.iter().any(|x| operators.iter().filter(|y| x == *y).count() > 1) {
-            e.panic_with_error(Error::InvalidOperators);
-        }
-        // fetch the remaining DAO balance
-        let dao_balance = e.get_dao_balance();
-        // actual unlocked amount can be different from the calculated percentage due to rounding errors
-        let mut total_unlocked = 0i128;
-        // calculate unlocked amount that goes to operators
-        let operators_unlocked = calc_share(&e, dao_balance, OPERATORS_SHARE);
-        // the amount a single operator would get
-        let unlock_per_operator = operators_unlocked / operators.len() as i128;
-        // update available balances for every operator
-        for operator in operators.iter() {
-            // increase outstanding available balance
-            update_available_balance(&e, &operator, unlock_per_operator);
-            total_unlocked = sum(&e, total_unlocked, unlock_per_operator);
-        }
-        // get developer unlocked amount
-        let developer_unlocked = calc_share(&e, dao_balance, DEVELOPERS_SHARE);
-        // increase outstanding developer available balance
-        update_available_balance(&e, &developer, developer_unlocked);
-        total_unlocked = sum(&e, total_unlocked, developer_unlocked);
-        // add week to last unlock date
-        e.set_last_unlock(last_unlock + UNLOCK_PERIOD as u64);
-        // update dao balance
-        e.set_dao_balance(dao_balance - total_unlocked);
+    /// Panics if the caller doesn't match governor address
+    /// Panics if the quorum or threshold is invalid
+    /// Panics if the voting rules are not set for all categories
+    pub fn set_voting_rules(e: Env, voting_rules: Map<BallotCategory, VotingRules>) {
+        e.panic_if_not_role(Role::Governor, &action_hash(&e, "set_voting_rules", (voting_rules.clone(),).into_val(&e)));
+        schedule_change(&e, ParamChange::VotingRules(voting_rules));
+    }
 
-        // publish unlock event
-        e.events().publish(
-            (
-                REFLECTOR,
-                symbol_short!("dao"),
-                symbol_short!("unlocked")
-            ),
-            ()
-        );
+    /// Sets the emission model used to calculate the amount unlocked every period
+    /// The change is queued if the timelock delay is set, see `execute_change`
+    /// Requires governor permissions
+    ///
+    /// # Arguments
+    ///
+    /// * `schedule` - Emission schedule
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match governor address
+    /// Panics if the schedule parameters are invalid
+    pub fn set_emission_schedule(e: Env, schedule: EmissionSchedule) {
+        e.panic_if_not_role(Role::Governor, &action_hash(&e, "set_emission_schedule", (schedule.clone(),).into_val(&e)));
+        schedule_change(&e, ParamChange::EmissionSchedule(schedule));
     }
 
-    /// Fetches the DAO tokens amount available for claiming
+    /// Calculates the amount of DAO tokens emitted at a given unlock epoch
+    /// The remaining DAO balance is projected from the current epoch assuming no other changes
     ///
     /// # Arguments
     ///
-    /// * `claimant` - Claimant's account address
+    /// * `epoch` - Unlock epoch, the number of unlocks executed before
     ///
     /// # Returns
     ///
-    /// * `available` - Amount of DAO tokens available for claiming
+    /// * `emission` - Amount of DAO tokens emitted
     ///
     /// # Panics
     ///
-    /// Panics if the caller doesn't match the claimant address
-    pub fn available(e: Env, claimant: Address) -> i128 {
-        // check if the claimant authorized the operation
-        claimant.require_auth();
-        // return available balance
-        e.get_available_balance(&claimant)
+    /// Panics if the epoch has already passed
+    /// Panics if the epoch is too far ahead for the decay model
+    pub fn preview_emission(e: Env, epoch: u32) -> i128 {
+        let current_epoch = e.get_epoch();
+        if epoch < current_epoch {
+            e.panic_with_error(Error::InvalidEpoch);
+        }
+        let schedule = e.get_emission_schedule();
+        // escrowed deposits are excluded from the emission base
+        let mut dao_balance = (e.get_dao_balance() - e.get_escrowed()).max(0);
+        match &schedule {
+            EmissionSchedule::Decay(_) => {
+                // the decay emission depends on the remaining balance, so it is projected epoch by epoch
+                if epoch - current_epoch > MAX_PREVIEW_EPOCHS {
+                    e.panic_with_error(Error::InvalidEpoch);
+                }
+                for past_epoch in current_epoch..epoch {
+                    dao_balance -= calc_emission(&e, &schedule, past_epoch, dao_balance);
+                }
+            }
+            EmissionSchedule::Fixed(amount) => {
+                dao_balance -= mul(&e, *amount, (epoch - current_epoch) as i128);
+            }
+            EmissionSchedule::Halving(params) => {
+                // sum up the emission between halvings, the amount is constant within every segment
+                let mut segment_start = current_epoch;
+                let mut halvings = params.epochs.iter().filter(|halving_epoch| *halving_epoch <= current_epoch).count() as u32;
+                for halving_epoch in params.epochs.iter().filter(|halving_epoch| *halving_epoch > current_epoch) {
+                    let segment_end = halving_epoch.min(epoch);
+                    if segment_end > segment_start {
+                        let amount = params.amount.checked_shr(halvings).unwrap_or(0);
+                        dao_balance -= mul(&e, amount, (segment_end - segment_start) as i128);
+                        segment_start = segment_end;
+                    }
+                    halvings += 1;
+                }
+                if epoch > segment_start {
+                    let amount = params.amount.checked_shr(halvings).unwrap_or(0);
+                    dao_balance -= mul(&e, amount, (epoch - segment_start) as i128);
+                }
+            }
+        }
+        calc_emission(&e, &schedule, epoch, dao_balance)
     }
 
-    /// Claims tokens unlocked for a given account address
+    /// Sets the shares of the revenue pool distributed to operators and developers on unlock
+    /// The change is queued if the timelock delay is set, see `execute_change`
+    /// Requires governor permissions
     ///
     /// # Arguments
     ///
-    /// * `claimant` - Claimant's account address
-    /// * `to` - Destination address that will receive claimed tokens
-    /// * `amount` - Amount of tokens to claim
+    /// * `shares` - Revenue shares, 10000 is 100%
     ///
     /// # Panics
     ///
-    /// Panics if the caller doesn't match the claimant address
-    /// Panics if the claimed amount is larger than the available unlocked amount
-    pub fn claim(e: Env, claimant: Address, to: Address, amount: i128) {
-        // check if the claimant authorized the operation
-        claimant.require_auth();
-        // fetch the unlocked amount for the given claimant
-        let balance = e.get_available_balance(&claimant);
-        // check if the unlocked amount is sufficient
-        if amount <= 0 || balance < amount {
-            e.panic_with_error(Error::InvalidAmount);
-        }
-        // transfer tokens to the destination address
-        token(&e).transfer(&e.current_contract_address(), &to, &amount);
+    /// Panics if the caller doesn't match governor address
+    /// Panics if the total share exceeds 100%
+    pub fn set_revenue_shares(e: Env, shares: RevenueShares) {
+        e.panic_if_not_role(Role::Governor, &action_hash(&e, "set_revenue_shares", (shares,).into_val(&e)));
+        schedule_change(&e, ParamChange::RevenueShares(shares));
+    }
 
-        // update available balance
-        update_available_balance(&e, &claimant, -amount);
+    /// Sets the Reflector oracle contract address
+    /// Accepted AddAsset ballots are applied to the oracle automatically, given the DAO contract is the oracle admin
+    /// The change is queued if the timelock delay is set, see `execute_change`
+    /// Requires governor permissions
+    ///
+    /// # Arguments
+    ///
+    /// * `oracle` - Oracle contract address
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match governor address
+    pub fn set_oracle(e: Env, oracle: Address) {
+        e.panic_if_not_role(Role::Governor, &action_hash(&e, "set_oracle", (oracle.clone(),).into_val(&e)));
+        schedule_change(&e, ParamChange::Oracle(oracle));
     }
 
-    /// Create a new ballot
+    /// Sets the delay before queued parameter changes can be executed, zero delay applies changes immediately
+    /// The delay change itself goes through the timelock queue
+    /// Requires admin permissions
     ///
     /// # Arguments
     ///
-    /// * `params` - Ballot initialization parameters
+    /// * `delay` - Timelock delay in seconds
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match admin address
+    /// Panics if the delay exceeds the maximum allowed delay
+    pub fn set_timelock_delay(e: Env, delay: u64) {
+        e.panic_if_not_admin(&action_hash(&e, "set_timelock_delay", (delay,).into_val(&e)));
+        schedule_change(&e, ParamChange::TimelockDelay(delay));
+    }
+
+    /// Fetches the delay before queued parameter changes can be executed
     ///
     /// # Returns
     ///
-    /// * `ballot_id` - Unique ID of a newly created ballot
+    /// Timelock delay in seconds
+    pub fn get_timelock_delay(e: Env) -> u64 {
+        e.get_timelock_delay()
+    }
+
+    /// Fetches parameter changes waiting in the timelock queue
+    ///
+    /// # Returns
+    ///
+    /// Queued parameter changes ordered by ID
+    pub fn get_queued_changes(e: Env) -> Vec<QueuedChange> {
+        e.get_queued_changes().values()
+    }
+
+    /// Executes the queued parameter change once the timelock delay has elapsed
+    /// Can be triggered by anyone
+    ///
+    /// # Arguments
+    ///
+    /// * `change_id` - Queued change ID
     ///
     /// # Panics
     ///
-    /// Panics if the caller doesn't match the initiator address
-    pub fn create_ballot(e: Env, params: BallotInitParams) -> u64 {
-        params.initiator.require_auth();
-        // generate new ballot id
-        let ballot_id = e.get_last_ballot_id() + 1;
-        // calculate deposit requirements for the ballot
-        let deposit = e.get_deposit(params.category);
-        if params.title.len() < 10
-            || params.title.len() > 40
-            || params.description.len() < 10
-            || params.description.len() > 160
-        {
-            e.panic_with_error(Error::InvalidBallotParams);
+    /// Panics if the change is not found
+    /// Panics if the timelock delay has not elapsed yet
+    /// Panics if the change parameters are no longer valid
+    pub fn execute_change(e: Env, change_id: u64) {
+        let mut changes = e.get_queued_changes();
+        let queued = changes.get(change_id);
+        if queued.is_none() {
+            e.panic_with_error(Error::ChangeNotFound);
         }
-        // create a ballot object
-        let ballot = Ballot {
-            initiator: params.initiator,
-            status: BallotStatus::Draft,
-            category: params.category,
-            title: params.title,
-            description: params.description,
-            deposit,
-            created: e.ledger().timestamp(),
-        };
-        // transfer deposit to DAO fund
-        token(&e).transfer(&ballot.initiator, &e.current_contract_address(), &deposit);
-        // update internal DAO balance
-        update_dao_balance(&e, deposit);
-        // save ballot
-        e.set_ballot(ballot_id, &ballot);
-        // extend ballot TTL
-        e.extend_ballot_ttl(ballot_id, e.ledger().sequence() + BALLOT_RENTAL_PERIOD);
-        // update ID counter
-        e.set_last_ballot_id(ballot_id);
+        let queued = queued.unwrap();
+        if e.ledger().timestamp() < queued.eta {
+            e.panic_with_error(Error::TimelockActive);
+        }
+        changes.remove(change_id);
+        e.set_queued_changes(&changes);
+        apply_change(&e, queued.change);
 
-        // publish ballot event
+        // publish change executed event
         e.events().publish(
             (
                 REFLECTOR,
                 symbol_short!("dao"),
-                symbol_short!("ballot")
+                symbol_short!("chgexec")
             ),
-            ballot
+            change_id
         );
+    }
 
-        // return created ballot ID
-        ballot_id
+    /// Cancels the queued parameter change
+    /// Requires guardian permissions, or admin permissions for changes targeting the guardian role or the timelock delay
+    ///
+    /// # Arguments
+    ///
+    /// * `change_id` - Queued change ID
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match guardian address (or admin address for protected changes)
+    /// Panics if the change is not found
+    pub fn cancel_change(e: Env, change_id: u64) {
+        let mut changes = e.get_queued_changes();
+        let queued = changes.get(change_id);
+        let action = action_hash(&e, "cancel_change", (change_id,).into_val(&e));
+        match queued {
+            // the guardian can't block its own replacement or a timelock delay update
+            Some(QueuedChange { change: ParamChange::SetRole(Role::Guardian, _), .. })
+            | Some(QueuedChange { change: ParamChange::RevokeRole(Role::Guardian), .. })
+            | Some(QueuedChange { change: ParamChange::TimelockDelay(_), .. }) => e.panic_if_not_admin(&action),
+            _ => e.panic_if_not_role(Role::Guardian, &action)
+        }
+        if !changes.contains_key(change_id) {
+            e.panic_with_error(Error::ChangeNotFound);
+        }
+        changes.remove(change_id);
+        e.set_queued_changes(&changes);
+
+        // publish change cancelled event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("chgcancel")
+            ),
+            change_id
+        );
     }
 
-    /// Fetch the ballot by its unique ID
+    /// Sets operators' account addresses receiving the unlocked tokens
+    /// The change is queued if the timelock delay is set, see `execute_change`
+    /// Requires governor permissions
     ///
     /// # Arguments
     ///
-    /// * `ballot_id` - Unique ballot ID
+    /// * `operators` - Operators' account addresses
     ///
-    /// # Returns
+    /// # Panics
     ///
-    /// * `ballot` - Ballot object
+    /// Panics if the caller doesn't match governor address
+    /// Panics if the operators list is empty or not unique
+    pub fn set_operators(e: Env, operators: Vec<Address>) {
+        e.panic_if_not_role(Role::Governor, &action_hash(&e, "set_operators", (operators.clone(),).into_val(&e)));
+        schedule_change(&e, ParamChange::Operators(operators));
+    }
+
+    /// Sets developer organizations receiving the developers' share of unlocked tokens
+    /// The change is queued if the timelock delay is set, see `execute_change`
+    /// Requires governor permissions
+    ///
+    /// # Arguments
+    ///
+    /// * `developers` - Map of developer account addresses to their splits, 10000 is 100%
     ///
     /// # Panics
     ///
-    /// Panics if the ballot is not found
-    pub fn get_ballot(e: Env, ballot_id: u64) -> Ballot {
-        get_ballot(&e, ballot_id)
+    /// Panics if the caller doesn't match governor address
+    /// Panics if the map is empty, contains zero splits, or the splits don't sum up to 100%
+    pub fn set_developers(e: Env, developers: Map<Address, u32>) {
+        e.panic_if_not_role(Role::Governor, &action_hash(&e, "set_developers", (developers.clone(),).into_val(&e)));
+        schedule_change(&e, ParamChange::Developers(developers));
     }
 
-    /// Retract the proposal and initiate the deposit refund
+    /// Sets the reward credited from the DAO treasury to the account triggering the unlock
+    /// The change is queued if the timelock delay is set, see `execute_change`
+    /// Requires governor permissions
     ///
     /// # Arguments
     ///
-    /// * `ballot_id` - Unique ballot ID
+    /// * `amount` - Keeper reward amount, zero disables the reward
     ///
     /// # Panics
     ///
-    /// Panics if the caller doesn't match the initiator address
-    /// Panics if the ballot status is in invalid state (not Draft or Rejected)
-    /// Panics if the voting period is not over
-    /// Panics if the ballot is not found
-    pub fn retract_ballot(e: Env, ballot_id: u64) {
-        // load the ballot
-        let mut ballot = get_ballot(&e, ballot_id);
-        // only initiator can retract the ballot
-        ballot.initiator.require_auth();
-        // calculate the refund amount based on the ballot status
-        let refunded = match ballot.status {
-            // if the proposal has been rejected by the DAO, the initiator receives 75% refund
-            BallotStatus::Rejected => get_value_percentage(&e, ballot.deposit, 75),
-            // if the DAO members haven't voted in a timely manner, the initiator receives extra 25% of the deposit
-            BallotStatus::Draft => {
-                // draft ballots can be retracted only after the voting period is over
-                if e.ledger().timestamp() - ballot.created < BALLOT_DURATION as u64 {
-                    e.panic_with_error(Error::RefundUnavailable);
-                }
-                get_value_percentage(&e, ballot.deposit, 125)
-            }
-            _ => e.panic_with_error(Error::RefundUnavailable),
-        };
-        // refund tokens to the initiator address
-        token(&e).transfer(&e.current_contract_address(), &ballot.initiator, &refunded);
-        // update remaining DAO balance
-        update_dao_balance(&e, -refunded);
-        // update ballot status
-        ballot.status = BallotStatus::Retracted;
-        e.set_ballot(ballot_id, &ballot);
+    /// Panics if the caller doesn't match governor address
+    /// Panics if the amount is negative
+    pub fn set_keeper_reward(e: Env, amount: i128) {
+        e.panic_if_not_role(Role::Governor, &action_hash(&e, "set_keeper_reward", (amount,).into_val(&e)));
+        schedule_change(&e, ParamChange::KeeperReward(amount));
+    }
 
-        // publish retracted event
+    /// Assigns an operational role to the account address
+    /// The change is queued if the timelock delay is set, see `execute_change`
+    /// Requires admin permissions
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - Role to assign
+    /// * `address` - Account address receiving the role
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match admin address
+    pub fn set_role(e: Env, role: Role, address: Address) {
+        e.panic_if_not_admin(&action_hash(&e, "set_role", (role, address.clone()).into_val(&e)));
+        schedule_change(&e, ParamChange::SetRole(role, address));
+    }
+
+    /// Revokes an operational role, the role falls back to the admin
+    /// The change is queued if the timelock delay is set, see `execute_change`
+    /// Requires admin permissions
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - Role to revoke
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match admin address
+    pub fn revoke_role(e: Env, role: Role) {
+        e.panic_if_not_admin(&action_hash(&e, "revoke_role", (role,).into_val(&e)));
+        schedule_change(&e, ParamChange::RevokeRole(role));
+    }
+
+    /// Fetches the account address holding the role
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - Role
+    ///
+    /// # Returns
+    ///
+    /// Role holder address, or the admin address if the role has not been assigned and the signer set is not configured
+    pub fn get_role(e: Env, role: Role) -> Option<Address> {
+        e.get_role(role).or_else(|| if e.get_signers().is_some() { None } else { e.get_admin() })
+    }
+
+    /// Sets the signer set authorizing admin actions instead of the admin address
+    /// Once the signer set is configured, admin actions, governor and vote recorder actions, and actions of other roles
+    /// that have not been assigned require approvals of the threshold number of signers submitted with `approve`,
+    /// including the signer set changes
+    /// Assigned governor and vote recorder must authorize their actions in addition to the approvals, while assigned
+    /// guardian and treasurer act on their own
+    ///
+    /// # Arguments
+    ///
+    /// * `signer_set` - Signer addresses and the approval threshold
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match admin address, or the action is not approved by the current signer set
+    /// Panics if the signer set is empty, contains duplicates, or the threshold is out of range
+    pub fn set_signers(e: Env, signer_set: SignerSet) {
+        e.panic_if_not_admin(&action_hash(&e, "set_signers", (signer_set.clone(),).into_val(&e)));
+        let signers = &signer_set.signers;
+        let has_duplicates = signers.iter().enumerate().any(|(i, signer)| signers.first_index_of(&signer) != Some(i as u32));
+        if signers.is_empty() || has_duplicates || signer_set.threshold == 0 || signer_set.threshold > signers.len() {
+            e.panic_with_error(Error::InvalidSigners);
+        }
+        e.set_signers(&signer_set);
+
+        // publish signers updated event
         e.events().publish(
             (
                 REFLECTOR,
                 symbol_short!("dao"),
-                symbol_short!("retracted")
+                symbol_short!("signers")
             ),
-            ballot_id
+            signer_set
         );
+    }
 
+    /// Fetches the signer set authorizing admin actions
+    ///
+    /// # Returns
+    ///
+    /// Signer set if configured
+    pub fn get_signers(e: Env) -> Option<SignerSet> {
+        e.get_signers()
     }
 
-    /// Set ballot decision based on the operators voting (decision requires the majority of signatures)
-    /// Requires admin permissions
+    /// Approves an admin action on behalf of the signer
+    /// The action is identified by the SHA-256 hash of the XDR-encoded tuple of the function name and its arguments
+    /// Approvals expire if the action is not executed within 30 days
     ///
     /// # Arguments
     ///
-    /// * `ballot_id` - Unique ballot ID
-    /// * `accepted` - Whether the proposal has been accepted or rejected by the majority of operators
+    /// * `signer` - Signer account address
+    /// * `action` - Action hash
     ///
     /// # Panics
     ///
-    /// Panics if the caller doesn't match admin address
-    /// Panics if the ballot status is not Draft
-    /// Panics if the ballot is not found
-    pub fn vote(e: Env, ballot_id: u64, accepted: bool) {
-        // check admin permissions
-        e.panic_if_not_admin();
-        // fetch ballot
-        let mut ballot = get_ballot(&e, ballot_id);
-        // it shouldn't be closed
-        if ballot.status != BallotStatus::Draft {
-            e.panic_with_error(Error::BallotClosed);
+    /// Panics if the caller doesn't match the signer address
+    /// Panics if the signer is not in the signer set
+    pub fn approve(e: Env, signer: Address, action: BytesN<32>) {
+        signer.require_auth();
+        let signer_set = e.get_signers();
+        if signer_set.is_none() || !signer_set.unwrap().signers.contains(&signer) {
+            e.panic_with_error(Error::Unauthorized);
+        }
+        let mut approvals = e.get_approvals(&action);
+        if !approvals.contains(&signer) {
+            approvals.push_back(signer.clone());
+            e.set_approvals(&action, &approvals);
         }
-        // resolve new status
-        let new_status = if accepted {
-            BallotStatus::Accepted
-        } else {
-            BallotStatus::Rejected
-        };
-        // calculate the amount of DAO tokens to burn
-        let burn_amount = match new_status {
-            BallotStatus::Rejected => get_value_percentage(&e, ballot.deposit, 25),
-            BallotStatus::Accepted => ballot.deposit,
-            _ => e.panic_with_error(Error::BallotClosed),
-        };
-        // burn tokens from the deposit according to the decision
-        token(&e).burn(&e.current_contract_address(), &burn_amount);
-        // update current DAO balance
-        update_dao_balance(&e, -burn_amount);
-        // update ballot status
-        ballot.status = new_status;
-        e.set_ballot(ballot_id, &ballot);
 
-        // publish voted event
+        // publish action approved event
         e.events().publish(
             (
                 REFLECTOR,
                 symbol_short!("dao"),
-                symbol_short!("voted")
+                symbol_short!("approved"),
+                signer
             ),
-            (ballot_id, accepted)
+            action
         );
     }
-}
 
-fn set_deposit(e: &Env, deposit_params: Map<BallotCategory, i128>) {
-    for category in BallotCategory::iterator() {
-        let amount = deposit_params.get(category).unwrap_or(0);
-        if amount <= 0 {
+    /// Revokes the approval of an admin action that has not been executed yet
+    ///
+    /// # Arguments
+    ///
+    /// * `signer` - Signer account address
+    /// * `action` - Action hash
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match the signer address
+    /// Panics if the signer has not approved the action
+    pub fn revoke_approval(e: Env, signer: Address, action: BytesN<32>) {
+        signer.require_auth();
+        let mut approvals = e.get_approvals(&action);
+        match approvals.first_index_of(&signer) {
+            Some(index) => approvals.remove(index),
+            None => e.panic_with_error(Error::ApprovalNotFound),
+        };
+        if approvals.is_empty() {
+            e.remove_approvals(&action);
+        } else {
+            e.set_approvals(&action, &approvals);
+        }
+
+        // publish approval revoked event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("unapprove"),
+                signer
+            ),
+            action
+        );
+    }
+
+    /// Fetches signers that approved the admin action
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - Action hash
+    ///
+    /// # Returns
+    ///
+    /// Addresses of signers that approved the action
+    pub fn get_approvals(e: Env, action: BytesN<32>) -> Vec<Address> {
+        e.get_approvals(&action)
+    }
+
+    /// Pauses a group of contract functions
+    /// Requires guardian permissions
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - Function group to pause
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match guardian address
+    pub fn pause(e: Env, group: PauseGroup) {
+        e.panic_if_not_role(Role::Guardian, &action_hash(&e, "pause", (group,).into_val(&e)));
+        e.set_paused(group, true);
+
+        // publish paused event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("paused")
+            ),
+            group
+        );
+    }
+
+    /// Resumes a paused group of contract functions
+    /// Requires admin permissions, the guardian can't unpause
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - Function group to unpause
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match admin address
+    pub fn unpause(e: Env, group: PauseGroup) {
+        e.panic_if_not_admin(&action_hash(&e, "unpause", (group,).into_val(&e)));
+        e.set_paused(group, false);
+
+        // publish unpaused event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("unpaused")
+            ),
+            group
+        );
+    }
+
+    /// Checks whether a group of contract functions is paused
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - Function group
+    ///
+    /// # Returns
+    ///
+    /// True if the group is paused
+    pub fn is_paused(e: Env, group: PauseGroup) -> bool {
+        e.is_paused(group)
+    }
+
+    /// Unlocks tokens distributed to the developer organization and operators on a weekly basis
+    /// Along with the emission, distributes the configured shares of the revenue accrued since the last unlock
+    /// Can be triggered by anyone once the unlock period has elapsed, the caller receives the keeper reward
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - Account address triggering the unlock
+    ///
+    /// # Panics
+    ///
+    /// Panics if unlock has been paused by the guardian
+    /// Panics if the caller authorization is missing
+    /// Panics if the unlock process has been initiated too early
+    /// Panics if the operators or developer recipients have not been set
+    pub fn unlock(e: Env, caller: Address) {
+        e.panic_if_paused(PauseGroup::Unlock);
+        // check if the caller authorized the operation
+        caller.require_auth();
+        // retrieve last recorded unlock period timestamp
+        let last_unlock = e.get_last_unlock();
+        let now = e.ledger().timestamp();
+        // panic if the unlock routine has been initiated too early
+        if now - last_unlock < UNLOCK_PERIOD as u64 {
+            e.panic_with_error(Error::UnlockUnavailable);
+        }
+        // load recipients
+        let operators = e.get_operators();
+        if operators.is_empty() {
+            e.panic_with_error(Error::InvalidOperators);
+        }
+        let developers = e.get_developers();
+        if developers.is_empty() {
+            e.panic_with_error(Error::InvalidDevelopers);
+        }
+        // fetch the remaining DAO balance, escrowed deposits can't be distributed
+        let dao_balance = e.get_dao_balance();
+        let available = (dao_balance - e.get_escrowed()).max(0);
+        // calculate the emission according to the configured schedule
+        let epoch = e.get_epoch();
+        let emission = calc_emission(&e, &e.get_emission_schedule(), epoch, available);
+        // calculate unlocked amounts that go to operators and developers
+        let operators_unlocked = div(&e, mul(&e, emission, OPERATORS_SHARE), OPERATORS_SHARE + DEVELOPERS_SHARE);
+        let developers_unlocked = div(&e, mul(&e, emission, DEVELOPERS_SHARE), OPERATORS_SHARE + DEVELOPERS_SHARE);
+        // actual unlocked amount can be different from the calculated percentage due to rounding errors
+        let emission_unlocked = distribute(&e, &developers, &operators, operators_unlocked, developers_unlocked);
+        // credit the keeper reward from the remaining DAO balance
+        let keeper_reward = e.get_keeper_reward().min(available - emission_unlocked).max(0);
+        if keeper_reward > 0 {
+            update_available_balance(&e, &caller, keeper_reward);
+        }
+        // update dao balance
+        e.set_dao_balance(dao_balance - emission_unlocked - keeper_reward);
+        // distribute revenue accrued since the last unlock
+        let revenue_accrued = e.get_revenue_accrued();
+        let shares = e.get_revenue_shares();
+        let revenue_unlocked = distribute(
+            &e,
+            &developers,
+            &operators,
+            calc_share(&e, revenue_accrued, shares.operators as i128),
+            calc_share(&e, revenue_accrued, shares.developers as i128),
+        );
+        // the rest of the revenue remains in the revenue pool
+        update_revenue_balance(&e, -revenue_unlocked);
+        e.set_revenue_accrued(0);
+        // add week to last unlock date
+        e.set_last_unlock(last_unlock + UNLOCK_PERIOD as u64);
+        e.set_epoch(epoch + 1);
+
+        // publish keeper rewarded event
+        if keeper_reward > 0 {
+            e.events().publish(
+                (
+                    REFLECTOR,
+                    symbol_short!("dao"),
+                    symbol_short!("keeper")
+                ),
+                (caller, keeper_reward)
+            );
+        }
+
+        // publish unlock event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("unlocked")
+            ),
+            (emission_unlocked, revenue_unlocked)
+        );
+    }
+
+    /// Fetches the DAO tokens amount available for claiming, including vested tokens
+    ///
+    /// # Arguments
+    ///
+    /// * `claimant` - Claimant's account address
+    ///
+    /// # Returns
+    ///
+    /// * `available` - Amount of DAO tokens available for claiming
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match the claimant address
+    pub fn available(e: Env, claimant: Address) -> i128 {
+        // check if the claimant authorized the operation
+        claimant.require_auth();
+        // return available balance along with vested tokens that haven't been released yet
+        let releasable = e.get_vesting(&claimant).map_or(0, |schedule| calc_releasable(&e, &schedule));
+        sum(&e, e.get_available_balance(&claimant), releasable)
+    }
+
+    /// Claims tokens unlocked for a given account address
+    ///
+    /// # Arguments
+    ///
+    /// * `claimant` - Claimant's account address
+    /// * `to` - Destination address that will receive claimed tokens
+    /// * `amount` - Amount of tokens to claim
+    ///
+    /// # Panics
+    ///
+    /// Panics if claims have been paused by the guardian
+    /// Panics if the caller doesn't match the claimant address
+    /// Panics if the claimed amount is larger than the available unlocked amount
+    pub fn claim(e: Env, claimant: Address, to: Address, amount: i128) {
+        e.panic_if_paused(PauseGroup::Claims);
+        // check if the claimant authorized the operation
+        claimant.require_auth();
+        transfer_claimed(&e, &claimant, &to, amount);
+    }
+
+    /// Claims tokens unlocked for a given account address on behalf of the claimant
+    /// Tokens are always transferred to the payout address registered by the claimant
+    ///
+    /// # Arguments
+    ///
+    /// * `operator` - Claim operator account address
+    /// * `claimant` - Claimant's account address
+    /// * `amount` - Amount of tokens to claim
+    ///
+    /// # Panics
+    ///
+    /// Panics if claims have been paused by the guardian
+    /// Panics if the caller doesn't match the operator address
+    /// Panics if the operator is not the claim operator delegated by the claimant
+    /// Panics if the claimant has not set the payout address
+    /// Panics if the claimed amount is larger than the available unlocked amount
+    pub fn claim_for(e: Env, operator: Address, claimant: Address, amount: i128) {
+        e.panic_if_paused(PauseGroup::Claims);
+        // check if the operator authorized the operation
+        operator.require_auth();
+        if e.get_claim_operator(&claimant) != Some(operator) {
+            e.panic_with_error(Error::Unauthorized);
+        }
+        let payout = e.get_payout_address(&claimant);
+        if payout.is_none() {
+            e.panic_with_error(Error::PayoutNotSet);
+        }
+        transfer_claimed(&e, &claimant, &payout.unwrap(), amount);
+    }
+
+    /// Sets the default payout address receiving tokens claimed by the delegated claim operator
+    ///
+    /// # Arguments
+    ///
+    /// * `claimant` - Claimant's account address
+    /// * `payout` - Payout address
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match the claimant address
+    pub fn set_payout_address(e: Env, claimant: Address, payout: Address) {
+        claimant.require_auth();
+        e.set_payout_address(&claimant, &payout);
+
+        // publish payout address updated event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("payout"),
+                claimant
+            ),
+            payout
+        );
+    }
+
+    /// Revokes the payout address of the claimant
+    ///
+    /// # Arguments
+    ///
+    /// * `claimant` - Claimant's account address
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match the claimant address
+    /// Panics if the payout address has not been set
+    pub fn revoke_payout_address(e: Env, claimant: Address) {
+        claimant.require_auth();
+        let payout = e.get_payout_address(&claimant);
+        if payout.is_none() {
+            e.panic_with_error(Error::PayoutNotSet);
+        }
+        e.remove_payout_address(&claimant);
+
+        // publish payout address revoked event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("payoutrvk"),
+                claimant
+            ),
+            payout.unwrap()
+        );
+    }
+
+    /// Fetches the payout address of the claimant
+    ///
+    /// # Arguments
+    ///
+    /// * `claimant` - Claimant's account address
+    ///
+    /// # Returns
+    ///
+    /// Payout address if set
+    pub fn get_payout_address(e: Env, claimant: Address) -> Option<Address> {
+        e.get_payout_address(&claimant)
+    }
+
+    /// Delegates claiming to a claim operator that can claim tokens only to the claimant's payout address
+    ///
+    /// # Arguments
+    ///
+    /// * `claimant` - Claimant's account address
+    /// * `operator` - Claim operator account address
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match the claimant address
+    pub fn set_claim_operator(e: Env, claimant: Address, operator: Address) {
+        claimant.require_auth();
+        e.set_claim_operator(&claimant, &operator);
+
+        // publish claim operator updated event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("claimop"),
+                claimant
+            ),
+            operator
+        );
+    }
+
+    /// Revokes the claim operator of the claimant
+    ///
+    /// # Arguments
+    ///
+    /// * `claimant` - Claimant's account address
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match the claimant address
+    /// Panics if the claim operator has not been set
+    pub fn revoke_claim_operator(e: Env, claimant: Address) {
+        claimant.require_auth();
+        let operator = e.get_claim_operator(&claimant);
+        if operator.is_none() {
+            e.panic_with_error(Error::ClaimOperatorNotSet);
+        }
+        e.remove_claim_operator(&claimant);
+
+        // publish claim operator revoked event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("claimoprv"),
+                claimant
+            ),
+            operator.unwrap()
+        );
+    }
+
+    /// Sets the distribution mode for the unlock recipient
+    /// In push mode, unlocked tokens are transferred directly to the recipient (or its payout address) instead of
+    /// being credited to the available balance
+    ///
+    /// # Arguments
+    ///
+    /// * `recipient` - Recipient's account address
+    /// * `enabled` - Whether the push mode is enabled
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match the recipient address
+    /// Panics if the push mode is enabled for an account that is not an operator or a developer
+    pub fn set_push_mode(e: Env, recipient: Address, enabled: bool) {
+        recipient.require_auth();
+        // only unlock recipients can opt in, disabling is always allowed
+        if enabled && !e.get_operators().contains(&recipient) && !e.get_developers().contains_key(recipient.clone()) {
+            e.panic_with_error(Error::InvalidRecipient);
+        }
+        e.set_push_mode(&recipient, enabled);
+
+        // publish push mode updated event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("pushmode"),
+                recipient
+            ),
+            enabled
+        );
+    }
+
+    /// Checks whether the unlock recipient opted in for the push mode
+    ///
+    /// # Arguments
+    ///
+    /// * `recipient` - Recipient's account address
+    ///
+    /// # Returns
+    ///
+    /// True if unlocked tokens are transferred to the recipient directly
+    pub fn get_push_mode(e: Env, recipient: Address) -> bool {
+        e.get_push_mode(&recipient)
+    }
+
+    /// Fetches the claim operator of the claimant
+    ///
+    /// # Arguments
+    ///
+    /// * `claimant` - Claimant's account address
+    ///
+    /// # Returns
+    ///
+    /// Claim operator address if set
+    pub fn get_claim_operator(e: Env, claimant: Address) -> Option<Address> {
+        e.get_claim_operator(&claimant)
+    }
+
+    /// Creates a cliff-and-linear vesting schedule for a beneficiary, reserving tokens from the DAO treasury
+    /// Vested tokens are claimed by the beneficiary through the regular claim flow
+    /// Requires treasurer permissions
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Vesting schedule parameters
+    ///
+    /// # Panics
+    ///
+    /// Panics if claims have been paused by the guardian
+    /// Panics if the caller doesn't match treasurer address
+    /// Panics if the schedule parameters are invalid
+    /// Panics if the beneficiary already has a vesting schedule
+    /// Panics if the DAO balance is insufficient
+    pub fn create_vesting(e: Env, params: VestingParams) {
+        e.panic_if_paused(PauseGroup::Claims);
+        e.panic_if_not_role(Role::Treasurer, &action_hash(&e, "create_vesting", (params.clone(),).into_val(&e)));
+        if params.total <= 0 || params.duration == 0 || params.cliff > params.duration {
+            e.panic_with_error(Error::InvalidVestingParams);
+        }
+        // only one schedule per beneficiary
+        if e.get_vesting(&params.beneficiary).is_some() {
+            e.panic_with_error(Error::VestingExists);
+        }
+        // reserve tokens from the DAO treasury, escrowed deposits can't be reserved
+        if e.get_dao_balance() - e.get_escrowed() < params.total {
+            e.panic_with_error(Error::InvalidAmount);
+        }
+        update_dao_balance(&e, -params.total);
+        update_vesting_reserved(&e, params.total);
+        let schedule = VestingSchedule {
+            total: params.total,
+            start: params.start,
+            cliff: params.cliff,
+            duration: params.duration,
+            revocable: params.revocable,
+            released: 0,
+        };
+        e.set_vesting(&params.beneficiary, &schedule);
+
+        // publish vesting created event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("vesting")
+            ),
+            (params.beneficiary, schedule)
+        );
+    }
+
+    /// Revokes the vesting schedule, returning unvested tokens to the DAO treasury
+    /// Tokens vested before the revocation remain available for the beneficiary
+    /// Requires treasurer permissions
+    ///
+    /// # Arguments
+    ///
+    /// * `beneficiary` - Beneficiary account address
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match treasurer address
+    /// Panics if the vesting schedule is not found
+    /// Panics if the vesting schedule is irrevocable
+    pub fn revoke_vesting(e: Env, beneficiary: Address) {
+        // revocation stays available while claims are paused to cut off a compromised beneficiary
+        e.panic_if_not_role(Role::Treasurer, &action_hash(&e, "revoke_vesting", (beneficiary.clone(),).into_val(&e)));
+        let schedule = get_vesting(&e, &beneficiary);
+        if !schedule.revocable {
+            e.panic_with_error(Error::VestingIrrevocable);
+        }
+        // release tokens vested so far
+        let released = release_vested(&e, &beneficiary);
+        // return the rest to the DAO treasury
+        let unvested = schedule.total - schedule.released - released;
+        update_vesting_reserved(&e, -unvested);
+        update_dao_balance(&e, unvested);
+        e.remove_vesting(&beneficiary);
+
+        // publish vesting revoked event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("revoked")
+            ),
+            (beneficiary, unvested)
+        );
+    }
+
+    /// Fetches the vesting schedule of a beneficiary
+    ///
+    /// # Arguments
+    ///
+    /// * `beneficiary` - Beneficiary account address
+    ///
+    /// # Returns
+    ///
+    /// * `schedule` - Vesting schedule
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vesting schedule is not found
+    pub fn get_vesting(e: Env, beneficiary: Address) -> VestingSchedule {
+        get_vesting(&e, &beneficiary)
+    }
+
+    /// Adds tokens to the DAO treasury, extending the emission runway
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Donor account address
+    /// * `amount` - Amount of DAO tokens to add
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match the donor address
+    /// Panics if the amount is invalid
+    pub fn fund(e: Env, from: Address, amount: i128) {
+        // check if the donor authorized the operation
+        from.require_auth();
+        if amount <= 0 {
+            e.panic_with_error(Error::InvalidAmount);
+        }
+        // transfer tokens to the DAO contract
+        token(&e).transfer(&from, &e.current_contract_address(), &amount);
+        // update DAO balance
+        update_dao_balance(&e, amount);
+        // track cumulative donations
+        let donations = e.get_donations(&from);
+        e.set_donations(&from, sum(&e, donations, amount));
+
+        // publish funded event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("funded")
+            ),
+            (from, amount)
+        );
+    }
+
+    /// Fetches the total amount of DAO tokens added to the treasury by a donor
+    ///
+    /// # Arguments
+    ///
+    /// * `donor` - Donor account address
+    ///
+    /// # Returns
+    ///
+    /// * `amount` - Cumulative donated amount
+    pub fn get_donations(e: Env, donor: Address) -> i128 {
+        e.get_donations(&donor)
+    }
+
+    /// Reconciles internal DAO accounting with the token balance held by the contract
+    ///
+    /// # Returns
+    ///
+    /// * `report` - Solvency report
+    pub fn solvency_report(e: Env) -> SolvencyReport {
+        solvency_report(&e)
+    }
+
+    /// Fetches the total amount of DAO tokens available for claiming by all accounts
+    ///
+    /// # Returns
+    ///
+    /// * `liabilities` - Total outstanding claimable balances
+    pub fn get_liabilities(e: Env) -> i128 {
+        e.get_liabilities()
+    }
+
+    /// Moves tokens not accounted by the DAO (e.g. direct transfers) to the DAO treasury
+    /// Requires treasurer permissions
+    ///
+    /// # Returns
+    ///
+    /// * `synced` - Amount of DAO tokens added to the treasury
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match treasurer address
+    pub fn sync_surplus(e: Env) -> i128 {
+        e.panic_if_not_role(Role::Treasurer, &action_hash(&e, "sync_surplus", ().into_val(&e)));
+        let surplus = solvency_report(&e).surplus;
+        // nothing to sync if the contract has no surplus
+        if surplus <= 0 {
+            return 0;
+        }
+        update_dao_balance(&e, surplus);
+
+        // publish synced event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("synced")
+            ),
+            surplus
+        );
+        surplus
+    }
+
+    /// Moves the undistributed remainder of the revenue pool to the DAO treasury
+    /// Revenue accrued since the last unlock is reserved for the next distribution and can't be moved
+    /// Requires treasurer permissions
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Amount of DAO tokens to move
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match treasurer address
+    /// Panics if the amount is not positive or exceeds the undistributed revenue remainder
+    pub fn sweep_revenue(e: Env, amount: i128) {
+        e.panic_if_not_role(Role::Treasurer, &action_hash(&e, "sweep_revenue", (amount,).into_val(&e)));
+        let remainder = e.get_revenue_balance() - e.get_revenue_accrued();
+        if amount <= 0 || amount > remainder {
+            e.panic_with_error(Error::InvalidAmount);
+        }
+        update_revenue_balance(&e, -amount);
+        update_dao_balance(&e, amount);
+
+        // publish revenue swept event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("revswept")
+            ),
+            amount
+        );
+    }
+
+    /// Receives protocol revenue, e.g. subscription fees, and credits it to the revenue pool
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Address of the account or contract paying the revenue
+    /// * `amount` - Amount of DAO tokens to deposit
+    /// * `source` - Revenue source identifier
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match the payer address
+    /// Panics if the amount is invalid
+    pub fn deposit_revenue(e: Env, from: Address, amount: i128, source: Symbol) {
+        // check if the payer authorized the operation
+        from.require_auth();
+        if amount <= 0 {
+            e.panic_with_error(Error::InvalidAmount);
+        }
+        // transfer tokens to the DAO contract
+        token(&e).transfer(&from, &e.current_contract_address(), &amount);
+        // credit the revenue pool
+        update_revenue_balance(&e, amount);
+        let revenue_accrued = e.get_revenue_accrued();
+        e.set_revenue_accrued(sum(&e, revenue_accrued, amount));
+
+        // publish revenue event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("revenue")
+            ),
+            (from, amount, source)
+        );
+    }
+
+    /// Fetches the amount of DAO tokens in the revenue pool
+    ///
+    /// # Returns
+    ///
+    /// * `balance` - Revenue pool balance
+    pub fn get_revenue_balance(e: Env) -> i128 {
+        e.get_revenue_balance()
+    }
+
+    /// Create a new ballot
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Ballot initialization parameters
+    ///
+    /// # Returns
+    ///
+    /// * `ballot_id` - Unique ID of a newly created ballot
+    ///
+    /// # Panics
+    ///
+    /// Panics if ballot creation has been paused by the guardian
+    /// Panics if the caller doesn't match the initiator address
+    pub fn create_ballot(e: Env, params: BallotInitParams) -> u64 {
+        e.panic_if_paused(PauseGroup::Ballots);
+        params.initiator.require_auth();
+        // generate new ballot id
+        let ballot_id = e.get_last_ballot_id() + 1;
+        // calculate deposit requirements for the ballot
+        let deposit = e.get_deposit(params.category);
+        if params.title.len() < 10
+            || params.title.len() > 40
+            || params.description.len() < 10
+            || params.description.len() > 160
+        {
+            e.panic_with_error(Error::InvalidBallotParams);
+        }
+        // structured data should match the ballot category
+        validate_payload(&e, params.category, &params.payload);
+        // proposal document URI is validated separately from the description
+        if let Some(uri) = &params.uri {
+            if uri.len() < 10 || uri.len() > 256 {
+                e.panic_with_error(Error::InvalidBallotParams);
+            }
+        }
+        // multi-option ballots should have from 2 to 10 non-empty options
+        // only General ballots can have options, as action payloads can't depend on the winning option
+        if !params.options.is_empty()
+            && (params.category != BallotCategory::General
+                || params.options.len() < 2
+                || params.options.len() > 10
+                || params.options.iter().any(|option| option.is_empty() || option.len() > 40))
+        {
+            e.panic_with_error(Error::InvalidBallotParams);
+        }
+        // create a ballot object
+        let ballot = Ballot {
+            initiator: params.initiator,
+            status: BallotStatus::Draft,
+            category: params.category,
+            title: params.title,
+            payload: params.payload,
+            description: params.description,
+            content_hash: params.content_hash,
+            uri: params.uri,
+            deposit,
+            created: e.ledger().timestamp(),
+            votes_for: 0,
+            votes_against: 0,
+            rationale: VoteRationale::None,
+            options: params.options,
+            option_votes: Vec::new(&e),
+            outcome: None,
+            execution: ExecutionStatus::None,
+        };
+        // transfer deposit to DAO fund
+        token(&e).transfer(&ballot.initiator, &e.current_contract_address(), &deposit);
+        // update internal DAO balance
+        update_dao_balance(&e, deposit);
+        update_escrowed(&e, deposit);
+        // save ballot
+        e.set_ballot(ballot_id, &ballot);
+        // extend ballot TTL
+        e.extend_ballot_ttl(ballot_id, e.ledger().sequence() + BALLOT_RENTAL_PERIOD);
+        // update ID counter
+        e.set_last_ballot_id(ballot_id);
+
+        // publish ballot event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("ballot")
+            ),
+            ballot
+        );
+
+        // return created ballot ID
+        ballot_id
+    }
+
+    /// Fetch the ballot by its unique ID
+    ///
+    /// # Arguments
+    ///
+    /// * `ballot_id` - Unique ballot ID
+    ///
+    /// # Returns
+    ///
+    /// * `ballot` - Ballot object
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ballot is not found
+    pub fn get_ballot(e: Env, ballot_id: u64) -> Ballot {
+        get_ballot(&e, ballot_id)
+    }
+
+    /// Fetch Reflector nodes approved by the DAO
+    ///
+    /// # Returns
+    ///
+    /// * `nodes` - Approved nodes
+    pub fn get_nodes(e: Env) -> Vec<NodeInfo> {
+        e.get_nodes()
+    }
+
+    /// Check whether the asset has been approved for quoting by the DAO
+    ///
+    /// # Arguments
+    ///
+    /// * `asset` - Asset to check
+    ///
+    /// # Returns
+    ///
+    /// * `approved` - Whether the asset is approved
+    pub fn is_asset_approved(e: Env, asset: Asset) -> bool {
+        e.has_asset(&asset)
+    }
+
+    /// Fetch assets approved for quoting by the DAO
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - Number of assets to skip
+    /// * `limit` - Maximum number of assets to return
+    ///
+    /// # Returns
+    ///
+    /// * `assets` - Approved assets, removing an asset moves the last approved one into its place
+    pub fn list_assets(e: Env, offset: u32, limit: u32) -> Vec<Asset> {
+        e.get_assets(offset, limit)
+    }
+
+    /// Fetch the price feed by its unique ID
+    ///
+    /// # Arguments
+    ///
+    /// * `feed_id` - Unique feed ID
+    ///
+    /// # Returns
+    ///
+    /// * `feed` - Price feed descriptor
+    ///
+    /// # Panics
+    ///
+    /// Panics if the feed is not found
+    pub fn get_feed(e: Env, feed_id: u64) -> PriceFeed {
+        get_feed(&e, feed_id)
+    }
+
+    /// Fetch price feeds sanctioned by the DAO
+    ///
+    /// # Arguments
+    ///
+    /// * `active_only` - Whether to skip deactivated feeds
+    ///
+    /// # Returns
+    ///
+    /// * `feeds` - Price feed descriptors
+    pub fn get_feeds(e: Env, active_only: bool) -> Vec<PriceFeed> {
+        let mut feeds = Vec::new(&e);
+        for feed in e.get_feeds().iter() {
+            if feed.active || !active_only {
+                feeds.push_back(feed);
+            }
+        }
+        feeds
+    }
+
+    /// Activate or deactivate the price feed
+    /// Requires governor permissions
+    ///
+    /// # Arguments
+    ///
+    /// * `feed_id` - Unique feed ID
+    /// * `active` - New feed status
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller doesn't match governor address
+    /// Panics if the feed is not found
+    pub fn set_feed_status(e: Env, feed_id: u64, active: bool) {
+        e.panic_if_not_role(Role::Governor, &action_hash(&e, "set_feed_status", (feed_id, active).into_val(&e)));
+        let mut feed = get_feed(&e, feed_id);
+        feed.active = active;
+        e.set_feed(&feed);
+
+        // publish feed status event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("feedstat")
+            ),
+            (feed_id, active)
+        );
+    }
+
+    /// Retract the proposal and initiate the deposit refund
+    ///
+    /// # Arguments
+    ///
+    /// * `ballot_id` - Unique ballot ID
+    ///
+    /// # Panics
+    ///
+    /// Panics if ballots have been paused by the guardian
+    /// Panics if the caller doesn't match the initiator address
+    /// Panics if the ballot status is in invalid state (not Draft or Rejected)
+    /// Panics if the voting period is not over
+    /// Panics if the ballot is not found
+    pub fn retract_ballot(e: Env, ballot_id: u64) {
+        e.panic_if_paused(PauseGroup::Ballots);
+        // load the ballot
+        let mut ballot = get_ballot(&e, ballot_id);
+        // only initiator can retract the ballot
+        ballot.initiator.require_auth();
+        // calculate the refund amount based on the ballot status
+        let (refunded, released) = match ballot.status {
+            // if the proposal has been rejected by the DAO, the initiator receives 75% refund
+            BallotStatus::Rejected => (
+                get_value_percentage(&e, ballot.deposit, 75),
+                ballot.deposit - get_value_percentage(&e, ballot.deposit, 25),
+            ),
+            // if the DAO members haven't voted in a timely manner, the initiator receives extra 25% of the deposit
+            BallotStatus::Draft => {
+                // draft ballots can be retracted only after the voting period is over
+                if e.ledger().timestamp() - ballot.created < BALLOT_DURATION as u64 {
+                    e.panic_with_error(Error::RefundUnavailable);
+                }
+                // the extra part is paid from the DAO treasury, escrowed deposits of other ballots can't be used
+                let spendable = (e.get_dao_balance() - e.get_escrowed()).max(0);
+                let extra = get_value_percentage(&e, ballot.deposit, 25).min(spendable);
+                (ballot.deposit + extra, ballot.deposit)
+            }
+            _ => e.panic_with_error(Error::RefundUnavailable),
+        };
+        // refund tokens to the initiator address
+        token(&e).transfer(&e.current_contract_address(), &ballot.initiator, &refunded);
+        // update remaining DAO balance
+        update_dao_balance(&e, -refunded);
+        // the deposit is no longer escrowed
+        update_escrowed(&e, -released);
+        // update ballot status
+        ballot.status = BallotStatus::Retracted;
+        e.set_ballot(ballot_id, &ballot);
+
+        // publish retracted event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("retracted")
+            ),
+            ballot_id
+        );
+
+    }
+
+    /// Set ballot decision based on the operators voting results
+    /// The ballot is accepted if the share of votes in favor exceeds the approval threshold of its category
+    /// Requires vote recorder permissions
+    ///
+    /// # Arguments
+    ///
+    /// * `ballot_id` - Unique ballot ID
+    /// * `votes_for` - Number of operators voted in favor of the proposal
+    /// * `votes_against` - Number of operators voted against the proposal
+    /// * `rationale` - Explanation of the decision, a short text or a hash of an off-chain document
+    ///
+    /// # Panics
+    ///
+    /// Panics if voting has been paused by the guardian
+    /// Panics if the caller doesn't match vote recorder address
+    /// Panics if the ballot status is not Draft
+    /// Panics if the ballot is not found
+    /// Panics if the ballot has multiple options
+    /// Panics if the number of participating votes is lower than the category quorum
+    /// Panics if the rationale text is empty or too long
+    pub fn vote(e: Env, ballot_id: u64, votes_for: u32, votes_against: u32, rationale: VoteRationale) {
+        e.panic_if_paused(PauseGroup::Voting);
+        // check vote recorder permissions
+        e.panic_if_not_role(Role::VoteRecorder, &action_hash(&e, "vote", (ballot_id, votes_for, votes_against, rationale.clone()).into_val(&e)));
+        // fetch ballot
+        let mut ballot = get_open_ballot(&e, ballot_id, &rationale);
+        // multi-option ballots are resolved with vote_options
+        if !ballot.options.is_empty() {
+            e.panic_with_error(Error::InvalidVotes);
+        }
+        // check that enough votes have been cast
+        let total_votes = votes_for as i128 + votes_against as i128;
+        let rules = get_ballot_voting_rules(&e, &ballot, total_votes);
+        // the share of votes in favor should exceed the approval threshold
+        let accepted = mul(&e, votes_for as i128, PERCENTAGE_FACTOR) > mul(&e, rules.threshold as i128, total_votes);
+        // record the voting results
+        ballot.votes_for = votes_for;
+        ballot.votes_against = votes_against;
+        ballot.rationale = rationale.clone();
+        close_ballot(&e, ballot_id, &mut ballot, accepted);
+
+        // publish voted event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("voted")
+            ),
+            (ballot_id, accepted, rationale)
+        );
+    }
+
+    /// Resolve a multi-option ballot based on the operators voting results
+    /// The option with the most votes wins if its share of votes exceeds the approval threshold of the ballot category,
+    /// otherwise the ballot is rejected without a winning option
+    /// Requires vote recorder permissions
+    ///
+    /// # Arguments
+    ///
+    /// * `ballot_id` - Unique ballot ID
+    /// * `votes` - Number of operators voted for each option, in the order of ballot options
+    /// * `rationale` - Explanation of the decision, a short text or a hash of an off-chain document
+    ///
+    /// # Panics
+    ///
+    /// Panics if voting has been paused by the guardian
+    /// Panics if the caller doesn't match vote recorder address
+    /// Panics if the ballot status is not Draft
+    /// Panics if the ballot is not found
+    /// Panics if the ballot is binary or the votes don't match ballot options
+    /// Panics if the number of participating votes is lower than the category quorum
+    /// Panics if the rationale text is empty or too long
+    pub fn vote_options(e: Env, ballot_id: u64, votes: Vec<u32>, rationale: VoteRationale) {
+        e.panic_if_paused(PauseGroup::Voting);
+        // check vote recorder permissions
+        e.panic_if_not_role(Role::VoteRecorder, &action_hash(&e, "vote_options", (ballot_id, votes.clone(), rationale.clone()).into_val(&e)));
+        // fetch ballot
+        let mut ballot = get_open_ballot(&e, ballot_id, &rationale);
+        // votes should be provided for every option
+        if ballot.options.is_empty() || votes.len() != ballot.options.len() {
+            e.panic_with_error(Error::InvalidVotes);
+        }
+        // check that enough votes have been cast
+        let total_votes = votes.iter().fold(0i128, |total, v| sum(&e, total, v as i128));
+        let rules = get_ballot_voting_rules(&e, &ballot, total_votes);
+        // find the option with the most votes, a tie means there is no winner
+        let mut outcome = None;
+        let mut max_votes = 0u32;
+        for (index, option_votes) in votes.iter().enumerate() {
+            if option_votes > max_votes {
+                max_votes = option_votes;
+                outcome = Some(index as u32);
+            } else if option_votes == max_votes {
+                outcome = None;
+            }
+        }
+        // the share of votes for the winning option should exceed the approval threshold
+        if mul(&e, max_votes as i128, PERCENTAGE_FACTOR) <= mul(&e, rules.threshold as i128, total_votes) {
+            outcome = None;
+        }
+        // record the voting results
+        ballot.option_votes = votes;
+        ballot.outcome = outcome;
+        ballot.rationale = rationale.clone();
+        close_ballot(&e, ballot_id, &mut ballot, outcome.is_some());
+
+        // publish resolved event
+        e.events().publish(
+            (
+                REFLECTOR,
+                symbol_short!("dao"),
+                symbol_short!("resolved")
+            ),
+            (ballot_id, outcome, rationale)
+        );
+    }
+}
+
+fn validate_deposit(e: &Env, deposit_params: &Map<BallotCategory, i128>) {
+    for category in BallotCategory::iterator() {
+        if deposit_params.get(category).unwrap_or(0) <= 0 {
             e.panic_with_error(Error::InvalidAmount);
         }
-        e.set_deposit(category, amount);
+    }
+}
+
+fn set_deposit(e: &Env, deposit_params: Map<BallotCategory, i128>) {
+    validate_deposit(e, &deposit_params);
+    for category in BallotCategory::iterator() {
+        e.set_deposit(category, deposit_params.get(category).unwrap());
     }
     
     // publish updated event
@@ -399,6 +1687,424 @@ fn set_deposit(e: &Env, deposit_params: Map<BallotCategory, i128>) {
     );
 }
 
+fn validate_emission_schedule(e: &Env, schedule: &EmissionSchedule) {
+    let is_valid = match schedule {
+        EmissionSchedule::Decay(percentage) => *percentage > 0 && *percentage as i128 <= PERCENTAGE_FACTOR,
+        EmissionSchedule::Fixed(amount) => *amount > 0,
+        EmissionSchedule::Halving(params) =>
+            params.amount > 0
+                && params.epochs.iter().zip(params.epochs.iter().skip(1)).all(|(prev, next)| prev < next),
+    };
+    if !is_valid {
+        e.panic_with_error(Error::InvalidEmissionSchedule);
+    }
+}
+
+fn set_emission_schedule(e: &Env, schedule: EmissionSchedule) {
+    validate_emission_schedule(e, &schedule);
+    e.set_emission_schedule(&schedule);
+
+    // publish updated event
+    e.events().publish(
+        (
+            REFLECTOR,
+            symbol_short!("dao"),
+            symbol_short!("emission")
+        ),
+        schedule,
+    );
+}
+
+fn validate_revenue_shares(e: &Env, shares: &RevenueShares) {
+    if shares.operators as i128 + shares.developers as i128 > PERCENTAGE_FACTOR {
+        e.panic_with_error(Error::InvalidRevenueShares);
+    }
+}
+
+fn set_revenue_shares(e: &Env, shares: RevenueShares) {
+    validate_revenue_shares(e, &shares);
+    e.set_revenue_shares(&shares);
+
+    // publish updated event
+    e.events().publish(
+        (
+            REFLECTOR,
+            symbol_short!("dao"),
+            symbol_short!("revshares")
+        ),
+        shares,
+    );
+}
+
+fn validate_voting_rules(e: &Env, voting_rules: &Map<BallotCategory, VotingRules>) {
+    for category in BallotCategory::iterator() {
+        let rules = voting_rules.get(category);
+        if rules.is_none() {
+            e.panic_with_error(Error::InvalidVotingRules);
+        }
+        let rules = rules.unwrap();
+        // at least one vote is required, and the threshold can't make the acceptance impossible
+        if rules.quorum == 0 || rules.threshold as i128 >= PERCENTAGE_FACTOR {
+            e.panic_with_error(Error::InvalidVotingRules);
+        }
+    }
+}
+
+fn set_voting_rules(e: &Env, voting_rules: Map<BallotCategory, VotingRules>) {
+    validate_voting_rules(e, &voting_rules);
+    for category in BallotCategory::iterator() {
+        e.set_voting_rules(category, &voting_rules.get(category).unwrap());
+    }
+
+    // publish updated event
+    e.events().publish(
+        (
+            REFLECTOR,
+            symbol_short!("dao"),
+            symbol_short!("rules")
+        ),
+        voting_rules,
+    );
+}
+
+// validate the parameter change and apply it immediately, or queue it if the timelock delay is set
+fn schedule_change(e: &Env, change: ParamChange) {
+    validate_change(e, &change);
+    let delay = e.get_timelock_delay();
+    if delay == 0 {
+        apply_change(e, change);
+        return;
+    }
+    let id = e.get_last_change_id() + 1;
+    e.set_last_change_id(id);
+    let queued = QueuedChange {
+        id,
+        change,
+        eta: e.ledger().timestamp() + delay,
+    };
+    let mut changes = e.get_queued_changes();
+    changes.set(id, queued.clone());
+    e.set_queued_changes(&changes);
+
+    // publish change queued event
+    e.events().publish(
+        (
+            REFLECTOR,
+            symbol_short!("dao"),
+            symbol_short!("chgqueued")
+        ),
+        queued
+    );
+}
+
+fn validate_change(e: &Env, change: &ParamChange) {
+    match change {
+        ParamChange::Deposit(deposit_params) => validate_deposit(e, deposit_params),
+        ParamChange::VotingRules(voting_rules) => validate_voting_rules(e, voting_rules),
+        ParamChange::EmissionSchedule(schedule) => validate_emission_schedule(e, schedule),
+        ParamChange::RevenueShares(shares) => validate_revenue_shares(e, shares),
+        ParamChange::Operators(operators) => {
+            // operators list should be non-empty and unique
+            if operators.is_empty() || operators.iter().any(|x| operators.iter().filter(|y| x == *y).count() > 1) {
+                e.panic_with_error(Error::InvalidOperators);
+            }
+        }
+        ParamChange::Developers(developers) => {
+            // splits should be positive and sum up to 100%
+            let total = developers.values().iter().fold(0i128, |total, split| total + split as i128);
+            if developers.is_empty() || developers.values().contains(0) || total != PERCENTAGE_FACTOR {
+                e.panic_with_error(Error::InvalidDevelopers);
+            }
+        }
+        ParamChange::KeeperReward(amount) => {
+            if *amount < 0 {
+                e.panic_with_error(Error::InvalidAmount);
+            }
+        }
+        ParamChange::TimelockDelay(delay) => {
+            if *delay > MAX_TIMELOCK_DELAY {
+                e.panic_with_error(Error::InvalidTimelockDelay);
+            }
+        }
+        ParamChange::Oracle(_) | ParamChange::SetRole(_, _) | ParamChange::RevokeRole(_) => {}
+    }
+}
+
+fn apply_change(e: &Env, change: ParamChange) {
+    match change {
+        ParamChange::Deposit(deposit_params) => set_deposit(e, deposit_params),
+        ParamChange::VotingRules(voting_rules) => set_voting_rules(e, voting_rules),
+        ParamChange::EmissionSchedule(schedule) => set_emission_schedule(e, schedule),
+        ParamChange::RevenueShares(shares) => set_revenue_shares(e, shares),
+        ParamChange::Operators(operators) => {
+            e.set_operators(&operators);
+
+            // publish operators updated event
+            e.events().publish(
+                (
+                    REFLECTOR,
+                    symbol_short!("dao"),
+                    symbol_short!("operators")
+                ),
+                operators
+            );
+        }
+        ParamChange::Developers(developers) => {
+            e.set_developers(&developers);
+
+            // publish developers updated event
+            e.events().publish(
+                (
+                    REFLECTOR,
+                    symbol_short!("dao"),
+                    symbol_short!("devs")
+                ),
+                developers
+            );
+        }
+        ParamChange::KeeperReward(amount) => e.set_keeper_reward(amount),
+        ParamChange::Oracle(oracle) => {
+            e.set_oracle(&oracle);
+
+            // publish oracle updated event
+            e.events().publish(
+                (
+                    REFLECTOR,
+                    symbol_short!("dao"),
+                    symbol_short!("oracle")
+                ),
+                oracle
+            );
+        }
+        ParamChange::SetRole(role, address) => {
+            e.set_role(role, &address);
+
+            // publish role assigned event
+            e.events().publish(
+                (
+                    REFLECTOR,
+                    symbol_short!("dao"),
+                    symbol_short!("roleset"),
+                    role
+                ),
+                address
+            );
+        }
+        ParamChange::RevokeRole(role) => {
+            e.remove_role(role);
+
+            // publish role revoked event
+            e.events().publish(
+                (
+                    REFLECTOR,
+                    symbol_short!("dao"),
+                    symbol_short!("rolervk"),
+                    role
+                ),
+                ()
+            );
+        }
+        ParamChange::TimelockDelay(delay) => {
+            e.set_timelock_delay(delay);
+
+            // publish timelock delay updated event
+            e.events().publish(
+                (
+                    REFLECTOR,
+                    symbol_short!("dao"),
+                    symbol_short!("timelock")
+                ),
+                delay
+            );
+        }
+    }
+}
+
+// check that the ballot payload matches the category and contains valid data
+fn validate_payload(e: &Env, category: BallotCategory, payload: &BallotPayload) {
+    let is_valid = match (category, payload) {
+        (BallotCategory::AddNode, BallotPayload::AddNode(node)) =>
+            node.endpoint.len() >= 10
+                && node.endpoint.len() <= 256
+                && e.get_node(&node.pubkey).is_none(),
+        (BallotCategory::AddPriceFeed, BallotPayload::AddPriceFeed(feed)) =>
+            feed.resolution > 0 && feed.decimals <= 18 && feed.base != feed.quote,
+        (BallotCategory::AddAsset, BallotPayload::AddAsset(asset)) => !e.has_asset(asset),
+        (BallotCategory::General, BallotPayload::None) => true,
+        (BallotCategory::RemoveNode, BallotPayload::RemoveNode(pubkey)) => e.get_node(pubkey).is_some(),
+        (BallotCategory::RemoveAsset, BallotPayload::RemoveAsset(asset)) => e.has_asset(asset),
+        // neither the DAO contract nor the DAO token can be invoked, so the treasury can't be moved without accounting
+        (BallotCategory::GenericCall, BallotPayload::GenericCall(call)) =>
+            call.contract != e.current_contract_address() && call.contract != e.get_token(),
+        _ => false,
+    };
+    if !is_valid {
+        e.panic_with_error(Error::InvalidBallotParams);
+    }
+}
+
+// fetch the ballot that is open for voting
+fn get_open_ballot(e: &Env, ballot_id: u64, rationale: &VoteRationale) -> Ballot {
+    let ballot = get_ballot(e, ballot_id);
+    // it shouldn't be closed
+    if ballot.status != BallotStatus::Draft {
+        e.panic_with_error(Error::BallotClosed);
+    }
+    // validate the rationale text length
+    if let VoteRationale::Text(text) = rationale {
+        if text.is_empty() || text.len() > 160 {
+            e.panic_with_error(Error::InvalidRationale);
+        }
+    }
+    ballot
+}
+
+// fetch voting rules for the ballot category and check that the quorum has been reached
+fn get_ballot_voting_rules(e: &Env, ballot: &Ballot, total_votes: i128) -> VotingRules {
+    let rules = e.get_voting_rules(ballot.category);
+    if total_votes < rules.quorum as i128 {
+        e.panic_with_error(Error::QuorumNotReached);
+    }
+    rules
+}
+
+// apply the voting decision to the ballot deposit and save the ballot
+fn close_ballot(e: &Env, ballot_id: u64, ballot: &mut Ballot, accepted: bool) {
+    // resolve new status and calculate the amount of DAO tokens to burn
+    let (new_status, burn_amount) = if accepted {
+        (BallotStatus::Accepted, ballot.deposit)
+    } else {
+        (BallotStatus::Rejected, get_value_percentage(e, ballot.deposit, 25))
+    };
+    // burn tokens from the deposit according to the decision
+    token(e).burn(&e.current_contract_address(), &burn_amount);
+    // update current DAO balance
+    update_dao_balance(e, -burn_amount);
+    update_escrowed(e, -burn_amount);
+    // update ballot status
+    ballot.status = new_status;
+    // apply the accepted proposal
+    if accepted {
+        execute_ballot(e, ballot_id, ballot);
+    }
+    e.set_ballot(ballot_id, ballot);
+}
+
+// apply changes proposed by the accepted ballot
+fn execute_ballot(e: &Env, ballot_id: u64, ballot: &mut Ballot) {
+    match &ballot.payload {
+        BallotPayload::AddNode(node) => {
+            let node = NodeInfo {
+                pubkey: node.pubkey.clone(),
+                endpoint: node.endpoint.clone(),
+                joined: e.ledger().timestamp(),
+            };
+            e.set_node(&node);
+
+            // publish node added event
+            e.events().publish(
+                (
+                    REFLECTOR,
+                    symbol_short!("dao"),
+                    symbol_short!("nodeadd")
+                ),
+                node
+            );
+        }
+        BallotPayload::RemoveNode(pubkey) => {
+            e.remove_node(pubkey);
+
+            // publish node removed event
+            e.events().publish(
+                (
+                    REFLECTOR,
+                    symbol_short!("dao"),
+                    symbol_short!("noderem")
+                ),
+                pubkey.clone()
+            );
+        }
+        BallotPayload::AddPriceFeed(feed) => {
+            // the feed is identified by the ballot ID
+            let feed = PriceFeed {
+                id: ballot_id,
+                base: feed.base.clone(),
+                quote: feed.quote.clone(),
+                resolution: feed.resolution,
+                decimals: feed.decimals,
+                oracle: feed.oracle.clone(),
+                active: true,
+            };
+            e.set_feed(&feed);
+
+            // publish feed added event
+            e.events().publish(
+                (
+                    REFLECTOR,
+                    symbol_short!("dao"),
+                    symbol_short!("feedadd")
+                ),
+                feed
+            );
+        }
+        BallotPayload::AddAsset(asset) => {
+            if !e.has_asset(asset) {
+                e.add_asset(asset);
+                // add the asset to the governed oracle unless it's already quoted (e.g. re-added after removal)
+                if let Some(oracle) = e.get_oracle() {
+                    let oracle = OracleClient::new(e, &oracle);
+                    if !oracle.assets().contains(asset) {
+                        oracle.add_assets(&e.current_contract_address(), &Vec::from_array(e, [asset.clone()]));
+                    }
+                }
+            }
+
+            // publish asset added event
+            e.events().publish(
+                (
+                    REFLECTOR,
+                    symbol_short!("dao"),
+                    symbol_short!("assetadd")
+                ),
+                asset.clone()
+            );
+        }
+        BallotPayload::RemoveAsset(asset) => {
+            e.remove_asset(asset);
+
+            // publish asset removed event
+            e.events().publish(
+                (
+                    REFLECTOR,
+                    symbol_short!("dao"),
+                    symbol_short!("assetrem")
+                ),
+                asset.clone()
+            );
+        }
+        BallotPayload::GenericCall(call) => {
+            // failed invocation doesn't revert the decision, the result is recorded on the ballot
+            let result = e.try_invoke_contract::<Val, soroban_sdk::Error>(&call.contract, &call.function, call.args.clone());
+            let status = match result {
+                Ok(_) => ExecutionStatus::Succeeded,
+                Err(_) => ExecutionStatus::Failed,
+            };
+            ballot.execution = status;
+
+            // publish executed event
+            e.events().publish(
+                (
+                    REFLECTOR,
+                    symbol_short!("dao"),
+                    symbol_short!("executed")
+                ),
+                (ballot_id, status)
+            );
+        }
+        _ => {}
+    }
+}
+
 // fetch ballot from the persistent storage
 fn get_ballot(e: &Env, ballot_id: u64) -> Ballot {
     // fetch ballot by ID
@@ -410,26 +2116,222 @@ fn get_ballot(e: &Env, ballot_id: u64) -> Ballot {
     ballot.unwrap()
 }
 
+// fetch price feed from the registry
+fn get_feed(e: &Env, feed_id: u64) -> PriceFeed {
+    let feed = e.get_feed(feed_id);
+    // panic if not found
+    if feed.is_none() {
+        e.panic_with_error(Error::FeedNotFound);
+    }
+    feed.unwrap()
+}
+
 // create an instance of the SAC token client
-fn token(e: &Env) -> TokenClient {
+fn token(e: &Env) -> TokenClient<'_> {
     TokenClient::new(e, &e.get_token())
 }
 
+// hash of the admin action (function name and arguments) approved by the signer set
+fn action_hash(e: &Env, function: &str, args: Vec<Val>) -> BytesN<32> {
+    e.crypto().sha256(&(Symbol::new(e, function), args).to_xdr(e)).into()
+}
+
+// calculate the emission for a given epoch from the DAO balance excluding escrowed deposits, it can't exceed that balance
+fn calc_emission(e: &Env, schedule: &EmissionSchedule, epoch: u32, available: i128) -> i128 {
+    let emission = match schedule {
+        EmissionSchedule::Decay(percentage) => calc_share(e, available, *percentage as i128),
+        EmissionSchedule::Fixed(amount) => *amount,
+        EmissionSchedule::Halving(params) => {
+            // count halvings that happened up to the given epoch
+            let halvings = params.epochs.iter().filter(|halving_epoch| *halving_epoch <= epoch).count() as u32;
+            params.amount.checked_shr(halvings).unwrap_or(0)
+        }
+    };
+    emission.min(available).max(0)
+}
+
 // calculate percentage from a given amount
 fn calc_share(e: &Env, value: i128, percentage: i128) -> i128 {
     div(e, mul(e, value, percentage), PERCENTAGE_FACTOR)
 }
 
+// transfer claimed tokens to the destination address and debit the available balance of the claimant
+fn transfer_claimed(e: &Env, claimant: &Address, to: &Address, amount: i128) {
+    // move vested tokens to the available balance
+    release_vested(e, claimant);
+    // fetch the unlocked amount for the given claimant
+    let balance = e.get_available_balance(claimant);
+    // check if the unlocked amount is sufficient
+    if amount <= 0 || balance < amount {
+        e.panic_with_error(Error::InvalidAmount);
+    }
+    // transfer tokens to the destination address
+    token(e).transfer(&e.current_contract_address(), to, &amount);
+
+    // update available balance
+    update_available_balance(e, claimant, -amount);
+}
+
+// credit the amounts split between operators equally and between developers according to their splits
+// returns the total credited amount
+fn distribute(
+    e: &Env,
+    developers: &Map<Address, u32>,
+    operators: &Vec<Address>,
+    operators_amount: i128,
+    developers_amount: i128,
+) -> i128 {
+    let mut total = 0i128;
+    // the amount a single operator would get
+    let per_operator = operators_amount / operators.len() as i128;
+    // update available balances for every operator
+    for operator in operators.iter() {
+        credit_recipient(e, &operator, per_operator);
+        total = sum(e, total, per_operator);
+    }
+    // update available balances for every developer
+    for (developer, split) in developers.iter() {
+        let developer_amount = calc_share(e, developers_amount, split as i128);
+        credit_recipient(e, &developer, developer_amount);
+        total = sum(e, total, developer_amount);
+    }
+    total
+}
+
+// transfer the amount directly if the recipient opted in for push mode, otherwise increase the available balance
+// falls back to crediting the available balance if the transfer fails (e.g. missing trustline)
+fn credit_recipient(e: &Env, recipient: &Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    if e.get_push_mode(recipient) {
+        // push to the payout address if the recipient registered one
+        let to = e.get_payout_address(recipient).unwrap_or(recipient.clone());
+        let result = token(e).try_transfer(&e.current_contract_address(), &to, &amount);
+        if let Ok(Ok(())) = result {
+            // publish pushed event
+            e.events().publish(
+                (
+                    REFLECTOR,
+                    symbol_short!("dao"),
+                    symbol_short!("pushed"),
+                    recipient.clone()
+                ),
+                amount
+            );
+            return;
+        }
+    }
+    // increase outstanding available balance
+    update_available_balance(e, recipient, amount);
+
+    // publish credited event
+    e.events().publish(
+        (
+            REFLECTOR,
+            symbol_short!("dao"),
+            symbol_short!("credited"),
+            recipient.clone()
+        ),
+        amount
+    );
+}
+
 // update the balance available for claiming for a particular account
+// every crediting and debiting path should go through this function to keep the liabilities total consistent
 fn update_available_balance(e: &Env, address: &Address, amount: i128) {
     let balance = e.get_available_balance(address);
-    e.set_available_balance(address, sum(&e, balance, amount));
+    e.set_available_balance(address, sum(e, balance, amount));
+    // keep track of the total outstanding balances
+    let liabilities = e.get_liabilities();
+    e.set_liabilities(sum(e, liabilities, amount));
+}
+
+// fetch vesting schedule of the beneficiary
+fn get_vesting(e: &Env, beneficiary: &Address) -> VestingSchedule {
+    let schedule = e.get_vesting(beneficiary);
+    // panic if not found
+    if schedule.is_none() {
+        e.panic_with_error(Error::VestingNotFound);
+    }
+    schedule.unwrap()
+}
+
+// calculate the amount vested so far but not released yet
+fn calc_releasable(e: &Env, schedule: &VestingSchedule) -> i128 {
+    let now = e.ledger().timestamp();
+    // nothing is vested before the cliff ends
+    if now < schedule.start.saturating_add(schedule.cliff) {
+        return 0;
+    }
+    let elapsed = now - schedule.start;
+    let vested = if elapsed >= schedule.duration {
+        schedule.total
+    } else {
+        div(e, mul(e, schedule.total, elapsed as i128), schedule.duration as i128)
+    };
+    vested - schedule.released
+}
+
+// move vested tokens to the available balance of the beneficiary, returns the released amount
+fn release_vested(e: &Env, beneficiary: &Address) -> i128 {
+    let schedule = e.get_vesting(beneficiary);
+    if schedule.is_none() {
+        return 0;
+    }
+    let mut schedule = schedule.unwrap();
+    let releasable = calc_releasable(e, &schedule);
+    if releasable > 0 {
+        schedule.released = sum(e, schedule.released, releasable);
+        update_vesting_reserved(e, -releasable);
+        update_available_balance(e, beneficiary, releasable);
+        e.set_vesting(beneficiary, &schedule);
+    }
+    releasable
+}
+
+// update the total amount of tokens reserved for vesting schedules
+fn update_vesting_reserved(e: &Env, amount: i128) {
+    let reserved = e.get_vesting_reserved();
+    e.set_vesting_reserved(sum(e, reserved, amount));
+}
+
+// update the total amount of refundable ballot deposits
+fn update_escrowed(e: &Env, amount: i128) {
+    let escrowed = e.get_escrowed();
+    e.set_escrowed(sum(e, escrowed, amount));
+}
+
+// compare the token balance with the amounts accounted by the DAO
+fn solvency_report(e: &Env) -> SolvencyReport {
+    let token_balance = token(e).balance(&e.current_contract_address());
+    let dao_balance = e.get_dao_balance();
+    let liabilities = e.get_liabilities();
+    let vesting = e.get_vesting_reserved();
+    let revenue_balance = e.get_revenue_balance();
+    // escrowed deposits are included in the DAO balance
+    let accounted = sum(e, sum(e, sum(e, dao_balance, liabilities), vesting), revenue_balance);
+    SolvencyReport {
+        token_balance,
+        dao_balance,
+        liabilities,
+        escrowed: e.get_escrowed(),
+        vesting,
+        revenue_balance,
+        surplus: sum(e, token_balance, -accounted),
+    }
 }
 
 // update the remaining DAO balance
 fn update_dao_balance(e: &Env, amount: i128) {
     let dao_balance = e.get_dao_balance();
-    e.set_dao_balance(sum(&e, dao_balance, amount));
+    e.set_dao_balance(sum(e, dao_balance, amount));
+}
+
+// update the revenue pool balance
+fn update_revenue_balance(e: &Env, amount: i128) {
+    let revenue_balance = e.get_revenue_balance();
+    e.set_revenue_balance(sum(e, revenue_balance, amount));
 }
 
 // calculate the percentage of a given value with overflow check
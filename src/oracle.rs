@@ -0,0 +1,14 @@
+use soroban_sdk::{contractclient, Address, Env, Vec};
+
+use crate::types::asset::Asset;
+
+/// Admin interface of the Reflector oracle contract governed by the DAO
+#[allow(dead_code)]
+#[contractclient(name = "OracleClient")]
+pub trait Oracle {
+    /// Adds new assets to the list of quoted assets
+    fn add_assets(e: Env, admin: Address, assets: Vec<Asset>);
+
+    /// Returns the list of quoted assets
+    fn assets(e: Env) -> Vec<Asset>;
+}
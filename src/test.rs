@@ -1,10 +1,17 @@
 #![cfg(test)]
+#![allow(clippy::inconsistent_digit_grouping)]
 
 use super::*;
+use types::{
+    asset::Asset, content_hash::ContentHash, contract_call::ContractCall, execution_status::ExecutionStatus,
+    halving_params::HalvingParams, node_params::NodeParams, param_change::ParamChange, pause_group::PauseGroup,
+    price_feed::PriceFeed, price_feed_params::PriceFeedParams, revenue_shares::RevenueShares, role::Role,
+    signer_set::SignerSet, vesting_params::VestingParams,
+};
 use soroban_sdk::{
-    testutils::{storage::Persistent, Address as _, Ledger, LedgerInfo},
+    testutils::{storage::Persistent, Address as _, Events, IssuerFlags, Ledger, LedgerInfo, MockAuth, MockAuthInvoke},
     token::StellarAssetClient,
-    vec, Env, String,
+    vec, BytesN, Env, IntoVal, String,
 };
 
 fn init_contract_with_admin<'a>() -> (Env, DAOContractClient<'a>, ContractConfig) {
@@ -15,7 +22,10 @@ fn init_contract_with_admin<'a>() -> (Env, DAOContractClient<'a>, ContractConfig
     let contract_id = env.register(DAOContract, ());
     let client: DAOContractClient<'a> = DAOContractClient::new(&env, &contract_id);
 
-    let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let token = env.register_stellar_asset_contract_v2(admin.clone());
+    // allow revoking trustlines to simulate failing transfers
+    token.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let token = token.address();
 
     env.mock_all_auths();
 
@@ -39,14 +49,29 @@ fn init_contract_with_admin<'a>() -> (Env, DAOContractClient<'a>, ContractConfig
     let init_data = ContractConfig {
         admin: admin.clone(),
         token,
-        amount: amount,
+        amount,
         deposit_params: Map::from_array(&env, [
             (BallotCategory::AddNode, 50_000_0000000),
             (BallotCategory::AddPriceFeed, 100_000_0000000),
             (BallotCategory::AddAsset, 5_000_0000000),
             (BallotCategory::General, 10_000_0000000),
+            (BallotCategory::RemoveNode, 50_000_0000000),
+            (BallotCategory::RemoveAsset, 5_000_0000000),
+            (BallotCategory::GenericCall, 10_000_0000000),
+        ]),
+        voting_rules: Map::from_array(&env, [
+            (BallotCategory::AddNode, VotingRules { quorum: 5, threshold: 6666 }),
+            (BallotCategory::AddPriceFeed, VotingRules { quorum: 3, threshold: 5000 }),
+            (BallotCategory::AddAsset, VotingRules { quorum: 3, threshold: 5000 }),
+            (BallotCategory::General, VotingRules { quorum: 3, threshold: 5000 }),
+            (BallotCategory::RemoveNode, VotingRules { quorum: 5, threshold: 6666 }),
+            (BallotCategory::RemoveAsset, VotingRules { quorum: 3, threshold: 5000 }),
+            (BallotCategory::GenericCall, VotingRules { quorum: 5, threshold: 6666 }),
         ]),
+        emission_schedule: EmissionSchedule::Decay(15),
+        revenue_shares: RevenueShares { operators: 4000, developers: 1000 },
         start_date: 0,
+        oracle: None,
     };
 
     //set admin
@@ -55,6 +80,95 @@ fn init_contract_with_admin<'a>() -> (Env, DAOContractClient<'a>, ContractConfig
     (env, client, init_data)
 }
 
+#[contract]
+struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn init(e: Env, admin: Address) {
+        e.storage().instance().set(&symbol_short!("admin"), &admin);
+    }
+
+    pub fn add_assets(e: Env, admin: Address, assets: Vec<Asset>) {
+        let expected_admin: Address = e.storage().instance().get(&symbol_short!("admin")).unwrap();
+        assert_eq!(admin, expected_admin);
+        admin.require_auth();
+        let mut all_assets = Self::assets(e.clone());
+        for asset in assets.iter() {
+            // the oracle rejects assets that are already quoted
+            assert!(!all_assets.contains(&asset), "AssetAlreadyExists");
+            all_assets.push_back(asset);
+        }
+        e.storage().instance().set(&symbol_short!("assets"), &all_assets);
+    }
+
+    pub fn assets(e: Env) -> Vec<Asset> {
+        e.storage().instance().get(&symbol_short!("assets")).unwrap_or(Vec::new(&e))
+    }
+}
+
+#[contract]
+struct MockGoverned;
+
+#[contractimpl]
+impl MockGoverned {
+    pub fn set_admin(e: Env, admin: Address) {
+        e.storage().instance().set(&symbol_short!("admin"), &admin);
+    }
+
+    pub fn set_value(e: Env, value: u32) {
+        let admin: Address = e.storage().instance().get(&symbol_short!("admin")).unwrap();
+        admin.require_auth();
+        e.storage().instance().set(&symbol_short!("value"), &value);
+    }
+
+    pub fn value(e: Env) -> u32 {
+        e.storage().instance().get(&symbol_short!("value")).unwrap_or(0)
+    }
+}
+
+fn ballot_payload(env: &Env, category: BallotCategory) -> BallotPayload {
+    match category {
+        BallotCategory::AddNode => BallotPayload::AddNode(NodeParams {
+            pubkey: BytesN::from_array(env, &[1; 32]),
+            endpoint: String::from_str(env, "https://node.test"),
+        }),
+        BallotCategory::AddPriceFeed => BallotPayload::AddPriceFeed(PriceFeedParams {
+            base: Asset::Other(symbol_short!("USD")),
+            quote: Asset::Other(symbol_short!("BTC")),
+            resolution: 300,
+            decimals: 14,
+            oracle: Address::generate(env),
+        }),
+        BallotCategory::AddAsset => BallotPayload::AddAsset(Asset::Other(symbol_short!("BTC"))),
+        BallotCategory::General => BallotPayload::None,
+        BallotCategory::RemoveNode => BallotPayload::RemoveNode(BytesN::from_array(env, &[1; 32])),
+        BallotCategory::RemoveAsset => BallotPayload::RemoveAsset(Asset::Other(symbol_short!("BTC"))),
+        BallotCategory::GenericCall => BallotPayload::GenericCall(ContractCall {
+            contract: Address::generate(env),
+            function: symbol_short!("set_value"),
+            args: Vec::new(env),
+        }),
+    }
+}
+
+fn ballot_params(env: &Env, initiator: &Address, category: BallotCategory) -> BallotInitParams {
+    BallotInitParams {
+        category,
+        title: String::from_str(env, "Testing...."),
+        payload: ballot_payload(env, category),
+        description: String::from_str(env, "https://test.com"),
+        content_hash: ContentHash::None,
+        uri: None,
+        initiator: initiator.clone(),
+        options: Vec::new(env),
+    }
+}
+
+fn contract_error(error: Error) -> soroban_sdk::Error {
+    soroban_sdk::Error::from_contract_error(error as u32)
+}
+
 #[test]
 fn test() {
     let (env, client, config) = init_contract_with_admin();
@@ -72,8 +186,12 @@ fn test() {
     let ballot_id = client.create_ballot(&BallotInitParams {
         category: BallotCategory::AddAsset,
         title: String::from_str(&env, "Testing...."),
+        payload: BallotPayload::AddAsset(Asset::Other(symbol_short!("BTC"))),
         description: String::from_str(&env, "https://test.com"),
+        content_hash: ContentHash::None,
+        uri: None,
         initiator: owner.clone(),
+        options: Vec::new(&env),
     });
 
     env.as_contract(&client.address, || {
@@ -81,7 +199,7 @@ fn test() {
         assert_eq!(balance, 10_000_005_000_0000000);
     });
 
-    client.vote(&ballot_id, &true);
+    client.vote(&ballot_id, &3, &1, &VoteRationale::None);
 
     let ballot = client.get_ballot(&ballot_id);
     assert_eq!(ballot.status, BallotStatus::Accepted);
@@ -89,8 +207,12 @@ fn test() {
     let ballot_id = client.create_ballot(&BallotInitParams {
         category: BallotCategory::AddAsset,
         title: String::from_str(&env, "Test2....."),
+        payload: BallotPayload::AddAsset(Asset::Other(symbol_short!("ETH"))),
         description: String::from_str(&env, "https://test.com"),
+        content_hash: ContentHash::None,
+        uri: None,
         initiator: owner.clone(),
+        options: Vec::new(&env),
     });
 
     env.as_contract(&client.address, || {
@@ -116,8 +238,12 @@ fn test() {
     let ballot_id = client.create_ballot(&BallotInitParams {
         category: BallotCategory::AddAsset,
         title: String::from_str(&env, "Test2....."),
+        payload: BallotPayload::AddAsset(Asset::Other(symbol_short!("ETH"))),
         description: String::from_str(&env, "https://test.com"),
+        content_hash: ContentHash::None,
+        uri: None,
         initiator: owner.clone(),
+        options: Vec::new(&env),
     });
 
     env.as_contract(&client.address, || {
@@ -125,7 +251,7 @@ fn test() {
         assert_eq!(entry_ttl, ledger_sequence + BALLOT_RENTAL_PERIOD);
     });
 
-    client.vote(&ballot_id, &false);
+    client.vote(&ballot_id, &1, &2, &VoteRationale::None);
 
     client.retract_ballot(&ballot_id);
 
@@ -136,7 +262,9 @@ fn test() {
 
     let developer = Address::generate(&env);
     let operators = vec![&env, Address::generate(&env)];
-    client.unlock(&developer, &operators);
+    client.set_developers(&Map::from_array(&env, [(developer.clone(), 10000)]));
+    client.set_operators(&operators);
+    client.unlock(&developer);
 
     env.as_contract(&client.address, || {
         let balance = env.get_available_balance(&developer);
@@ -154,7 +282,7 @@ fn test() {
     });    
 
     //unlock again
-    client.unlock(&developer, &operators);
+    client.unlock(&developer);
 
     env.as_contract(&client.address, || {
         let last_unlock = env.get_last_unlock();
@@ -171,3 +299,1325 @@ fn test() {
         assert_eq!(balance, 0);
     });
 }
+
+#[test]
+fn test_voting_rules() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let owner = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&owner, &200_000_0000000);
+
+    let create_node_ballot = |seed: u8| {
+        let mut params = ballot_params(&env, &owner, BallotCategory::AddNode);
+        params.payload = BallotPayload::AddNode(NodeParams {
+            pubkey: BytesN::from_array(&env, &[seed; 32]),
+            endpoint: String::from_str(&env, "https://node.test"),
+        });
+        client.create_ballot(&params)
+    };
+
+    // quorum is not reached
+    let ballot_id = create_node_ballot(1);
+    let res = client.try_vote(&ballot_id, &3, &1, &VoteRationale::None);
+    assert_eq!(res, Err(Ok(contract_error(Error::QuorumNotReached))));
+    assert_eq!(client.get_ballot(&ballot_id).status, BallotStatus::Draft);
+
+    // 4 of 6 votes is enough for the two-thirds threshold
+    client.vote(&ballot_id, &4, &2, &VoteRationale::None);
+    let ballot = client.get_ballot(&ballot_id);
+    assert_eq!(ballot.status, BallotStatus::Accepted);
+    assert_eq!((ballot.votes_for, ballot.votes_against), (4, 2));
+
+    // 3 of 5 votes is not enough for the two-thirds threshold
+    let ballot_id = create_node_ballot(2);
+    client.vote(&ballot_id, &3, &2, &VoteRationale::None);
+    assert_eq!(client.get_ballot(&ballot_id).status, BallotStatus::Rejected);
+
+    // switch to simple majority
+    let mut voting_rules = config.voting_rules.clone();
+    voting_rules.set(BallotCategory::AddNode, VotingRules { quorum: 5, threshold: 5000 });
+    client.set_voting_rules(&voting_rules);
+
+    let ballot_id = create_node_ballot(3);
+    client.vote(&ballot_id, &3, &2, &VoteRationale::None);
+    assert_eq!(client.get_ballot(&ballot_id).status, BallotStatus::Accepted);
+
+    // voting rules should be set for every category
+    voting_rules.remove(BallotCategory::General);
+    let res = client.try_set_voting_rules(&voting_rules);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidVotingRules))));
+
+    // threshold can't exceed 100%
+    voting_rules.set(BallotCategory::General, VotingRules { quorum: 1, threshold: 10000 });
+    let res = client.try_set_voting_rules(&voting_rules);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidVotingRules))));
+}
+
+#[test]
+fn test_vote_rationale() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let owner = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&owner, &200_000_0000000);
+
+    // rationale text should not be empty
+    let ballot_id = client.create_ballot(&ballot_params(&env, &owner, BallotCategory::AddAsset));
+    let rationale = VoteRationale::Text(String::from_str(&env, ""));
+    let res = client.try_vote(&ballot_id, &1, &2, &rationale);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidRationale))));
+
+    let rationale = VoteRationale::Text(String::from_str(&env, "Asset liquidity is too low"));
+    client.vote(&ballot_id, &1, &2, &rationale);
+    assert_eq!(client.get_ballot(&ballot_id).rationale, rationale);
+
+    let ballot_id = client.create_ballot(&ballot_params(&env, &owner, BallotCategory::AddAsset));
+    let rationale = VoteRationale::Hash(BytesN::from_array(&env, &[1; 32]));
+    client.vote(&ballot_id, &2, &1, &rationale);
+    assert_eq!(client.get_ballot(&ballot_id).rationale, rationale);
+}
+
+#[test]
+fn test_multi_option_ballot() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let owner = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&owner, &200_000_0000000);
+
+    let mut params = ballot_params(&env, &owner, BallotCategory::General);
+    params.options = vec![
+        &env,
+        String::from_str(&env, "Provider A"),
+        String::from_str(&env, "Provider B"),
+        String::from_str(&env, "Provider C"),
+    ];
+
+    // binary voting is not applicable to multi-option ballots
+    let ballot_id = client.create_ballot(&params);
+    let res = client.try_vote(&ballot_id, &3, &1, &VoteRationale::None);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidVotes))));
+
+    // votes should be provided for every option
+    let res = client.try_vote_options(&ballot_id, &vec![&env, 3, 1], &VoteRationale::None);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidVotes))));
+
+    let res = client.try_vote_options(&ballot_id, &vec![&env, 1, 0, 1], &VoteRationale::None);
+    assert_eq!(res, Err(Ok(contract_error(Error::QuorumNotReached))));
+
+    client.vote_options(&ballot_id, &vec![&env, 1, 4, 1], &VoteRationale::None);
+    let ballot = client.get_ballot(&ballot_id);
+    assert_eq!(ballot.status, BallotStatus::Accepted);
+    assert_eq!(ballot.outcome, Some(1));
+
+    env.as_contract(&client.address, || {
+        // the whole deposit has been burned
+        assert_eq!(env.get_dao_balance(), config.amount);
+    });
+
+    // the winning option doesn't have the majority
+    let ballot_id = client.create_ballot(&params);
+    client.vote_options(&ballot_id, &vec![&env, 2, 3, 2], &VoteRationale::None);
+    let ballot = client.get_ballot(&ballot_id);
+    assert_eq!(ballot.status, BallotStatus::Rejected);
+    assert_eq!(ballot.outcome, None);
+
+    // a tie means there is no winner
+    let ballot_id = client.create_ballot(&params);
+    client.vote_options(&ballot_id, &vec![&env, 3, 3, 0], &VoteRationale::None);
+    assert_eq!(client.get_ballot(&ballot_id).outcome, None);
+
+    // rejected ballot deposit can be partially refunded
+    client.retract_ballot(&ballot_id);
+    assert_eq!(client.get_ballot(&ballot_id).status, BallotStatus::Retracted);
+
+    // options are not allowed for ballots with action payloads
+    let mut action_params = ballot_params(&env, &owner, BallotCategory::AddAsset);
+    action_params.options = params.options.clone();
+    let res = client.try_create_ballot(&action_params);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidBallotParams))));
+
+    // a single option is not allowed
+    params.options = vec![&env, String::from_str(&env, "Provider A")];
+    let res = client.try_create_ballot(&params);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidBallotParams))));
+}
+
+#[test]
+fn test_ballot_content_hash() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let owner = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&owner, &200_000_0000000);
+
+    let mut params = ballot_params(&env, &owner, BallotCategory::General);
+    params.description = String::from_str(&env, "Switch to the new price feed provider");
+    params.content_hash = ContentHash::Sha256(BytesN::from_array(&env, &[7; 32]));
+    params.uri = Some(String::from_str(&env, "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"));
+
+    let ballot_id = client.create_ballot(&params);
+    let ballot = client.get_ballot(&ballot_id);
+    assert_eq!(ballot.content_hash, params.content_hash);
+    assert_eq!(ballot.uri, params.uri);
+
+    // the creation event contains the proposal document hash
+    let (_, _, data) = env.events().all().last().unwrap();
+    let event_ballot: Ballot = data.into_val(&env);
+    assert_eq!(event_ballot.content_hash, params.content_hash);
+
+    // URI is validated independently
+    params.content_hash = ContentHash::None;
+    params.uri = Some(String::from_str(&env, "ipfs://"));
+    let res = client.try_create_ballot(&params);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidBallotParams))));
+}
+
+#[test]
+fn test_ballot_payload() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let owner = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&owner, &500_000_0000000);
+
+    // payload is stored with the ballot, removal requires a registered node or asset
+    let removals = [BallotCategory::RemoveNode, BallotCategory::RemoveAsset];
+    for category in BallotCategory::iterator().filter(|category| !removals.contains(category)) {
+        let params = ballot_params(&env, &owner, category);
+        let ballot_id = client.create_ballot(&params);
+        assert_eq!(client.get_ballot(&ballot_id).payload, params.payload);
+    }
+
+    // payload should match the category
+    let mut params = ballot_params(&env, &owner, BallotCategory::AddAsset);
+    params.payload = ballot_payload(&env, BallotCategory::AddNode);
+    let res = client.try_create_ballot(&params);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidBallotParams))));
+
+    params.payload = BallotPayload::None;
+    let res = client.try_create_ballot(&params);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidBallotParams))));
+
+    // price feed base and quote should differ
+    let mut params = ballot_params(&env, &owner, BallotCategory::AddPriceFeed);
+    params.payload = BallotPayload::AddPriceFeed(PriceFeedParams {
+        base: Asset::Other(symbol_short!("USD")),
+        quote: Asset::Other(symbol_short!("USD")),
+        resolution: 300,
+        decimals: 14,
+        oracle: Address::generate(&env),
+    });
+    let res = client.try_create_ballot(&params);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidBallotParams))));
+
+    // node endpoint is required
+    let mut params = ballot_params(&env, &owner, BallotCategory::AddNode);
+    params.payload = BallotPayload::AddNode(NodeParams {
+        pubkey: BytesN::from_array(&env, &[1; 32]),
+        endpoint: String::from_str(&env, ""),
+    });
+    let res = client.try_create_ballot(&params);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidBallotParams))));
+}
+
+#[test]
+fn test_node_registry() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let owner = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&owner, &500_000_0000000);
+
+    // only registered nodes can be removed
+    let res = client.try_create_ballot(&ballot_params(&env, &owner, BallotCategory::RemoveNode));
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidBallotParams))));
+
+    // rejected ballots don't affect the registry
+    let ballot_id = client.create_ballot(&ballot_params(&env, &owner, BallotCategory::AddNode));
+    client.vote(&ballot_id, &2, &3, &VoteRationale::None);
+    assert!(client.get_nodes().is_empty());
+
+    env.ledger().set_timestamp(1000);
+
+    let ballot_id = client.create_ballot(&ballot_params(&env, &owner, BallotCategory::AddNode));
+    client.vote(&ballot_id, &5, &0, &VoteRationale::None);
+    assert_eq!(
+        client.get_nodes(),
+        vec![
+            &env,
+            NodeInfo {
+                pubkey: BytesN::from_array(&env, &[1; 32]),
+                endpoint: String::from_str(&env, "https://node.test"),
+                joined: 1000,
+            }
+        ]
+    );
+
+    // the node is already registered
+    let res = client.try_create_ballot(&ballot_params(&env, &owner, BallotCategory::AddNode));
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidBallotParams))));
+
+    let ballot_id = client.create_ballot(&ballot_params(&env, &owner, BallotCategory::RemoveNode));
+    client.vote(&ballot_id, &5, &0, &VoteRationale::None);
+    assert!(client.get_nodes().is_empty());
+}
+
+#[test]
+fn test_asset_registry() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let owner = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&owner, &500_000_0000000);
+
+    let btc = Asset::Other(symbol_short!("BTC"));
+    let eth = Asset::Other(symbol_short!("ETH"));
+    let xlm = Asset::Stellar(Address::generate(&env));
+
+    // only approved assets can be removed
+    let res = client.try_create_ballot(&ballot_params(&env, &owner, BallotCategory::RemoveAsset));
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidBallotParams))));
+
+    for asset in [btc.clone(), eth.clone(), xlm.clone()] {
+        let mut params = ballot_params(&env, &owner, BallotCategory::AddAsset);
+        params.payload = BallotPayload::AddAsset(asset.clone());
+        let ballot_id = client.create_ballot(&params);
+        assert!(!client.is_asset_approved(&asset));
+        client.vote(&ballot_id, &3, &0, &VoteRationale::None);
+        assert!(client.is_asset_approved(&asset));
+    }
+
+    assert_eq!(client.list_assets(&0, &10), vec![&env, btc.clone(), eth.clone(), xlm.clone()]);
+    assert_eq!(client.list_assets(&1, &1), vec![&env, eth.clone()]);
+    assert_eq!(client.list_assets(&2, &10), vec![&env, xlm.clone()]);
+    assert!(client.list_assets(&5, &10).is_empty());
+
+    // the asset is already approved
+    let res = client.try_create_ballot(&ballot_params(&env, &owner, BallotCategory::AddAsset));
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidBallotParams))));
+
+    let ballot_id = client.create_ballot(&ballot_params(&env, &owner, BallotCategory::RemoveAsset));
+    client.vote(&ballot_id, &3, &0, &VoteRationale::None);
+    assert!(!client.is_asset_approved(&btc));
+    // the last approved asset takes the place of the removed one
+    assert_eq!(client.list_assets(&0, &10), vec![&env, xlm.clone(), eth.clone()]);
+
+    env.as_contract(&client.address, || {
+        // registry entries are kept in persistent storage
+        assert!(env.storage().persistent().has(&("assets", eth)));
+        assert!(!env.storage().persistent().has(&("assets", btc)));
+        assert!(env.storage().persistent().has(&("asset_index", 1u32)));
+        assert!(!env.storage().persistent().has(&("asset_index", 2u32)));
+    });
+}
+
+#[test]
+fn test_price_feed_registry() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let owner = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&owner, &500_000_0000000);
+
+    let params = ballot_params(&env, &owner, BallotCategory::AddPriceFeed);
+    let feed_params = match params.payload.clone() {
+        BallotPayload::AddPriceFeed(feed_params) => feed_params,
+        _ => unreachable!(),
+    };
+
+    // rejected ballots don't affect the registry
+    let ballot_id = client.create_ballot(&params);
+    client.vote(&ballot_id, &1, &2, &VoteRationale::None);
+    assert!(client.get_feeds(&false).is_empty());
+
+    let ballot_id = client.create_ballot(&params);
+    client.vote(&ballot_id, &3, &0, &VoteRationale::None);
+
+    let feed = PriceFeed {
+        id: ballot_id,
+        base: feed_params.base,
+        quote: feed_params.quote,
+        resolution: feed_params.resolution,
+        decimals: feed_params.decimals,
+        oracle: feed_params.oracle,
+        active: true,
+    };
+    assert_eq!(client.get_feed(&ballot_id), feed);
+    assert_eq!(client.get_feeds(&true), vec![&env, feed.clone()]);
+
+    client.set_feed_status(&ballot_id, &false);
+    assert!(!client.get_feed(&ballot_id).active);
+    assert!(client.get_feeds(&true).is_empty());
+    assert_eq!(client.get_feeds(&false).len(), 1);
+
+    client.set_feed_status(&ballot_id, &true);
+    assert_eq!(client.get_feeds(&true), vec![&env, feed]);
+
+    let res = client.try_set_feed_status(&(ballot_id + 1), &false);
+    assert_eq!(res, Err(Ok(contract_error(Error::FeedNotFound))));
+}
+
+#[test]
+fn test_oracle_execution() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let owner = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&owner, &500_000_0000000);
+
+    let oracle_id = env.register(MockOracle, ());
+    let oracle = MockOracleClient::new(&env, &oracle_id);
+    oracle.init(&client.address);
+    client.set_oracle(&oracle_id);
+
+    // rejected ballots are not applied
+    let ballot_id = client.create_ballot(&ballot_params(&env, &owner, BallotCategory::AddAsset));
+    client.vote(&ballot_id, &1, &2, &VoteRationale::None);
+    assert!(oracle.assets().is_empty());
+
+    // accepted ballot adds the asset to the oracle in the same invocation
+    let ballot_id = client.create_ballot(&ballot_params(&env, &owner, BallotCategory::AddAsset));
+    let second_id = client.create_ballot(&ballot_params(&env, &owner, BallotCategory::AddAsset));
+    client.vote(&ballot_id, &3, &0, &VoteRationale::None);
+    assert_eq!(oracle.assets(), vec![&env, Asset::Other(symbol_short!("BTC"))]);
+    assert!(client.is_asset_approved(&Asset::Other(symbol_short!("BTC"))));
+
+    // concurrent ballot for the same asset doesn't add it to the oracle twice
+    client.vote(&second_id, &3, &0, &VoteRationale::None);
+    assert_eq!(client.get_ballot(&second_id).status, BallotStatus::Accepted);
+    assert_eq!(oracle.assets(), vec![&env, Asset::Other(symbol_short!("BTC"))]);
+
+    // removed assets stay quoted by the oracle and can be re-approved
+    let ballot_id = client.create_ballot(&ballot_params(&env, &owner, BallotCategory::RemoveAsset));
+    client.vote(&ballot_id, &3, &0, &VoteRationale::None);
+    assert!(!client.is_asset_approved(&Asset::Other(symbol_short!("BTC"))));
+    let ballot_id = client.create_ballot(&ballot_params(&env, &owner, BallotCategory::AddAsset));
+    client.vote(&ballot_id, &3, &0, &VoteRationale::None);
+    assert!(client.is_asset_approved(&Asset::Other(symbol_short!("BTC"))));
+    assert_eq!(oracle.assets(), vec![&env, Asset::Other(symbol_short!("BTC"))]);
+}
+
+#[test]
+fn test_generic_call_execution() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let owner = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&owner, &500_000_0000000);
+
+    let governed_id = env.register(MockGoverned, ());
+    let governed = MockGovernedClient::new(&env, &governed_id);
+    governed.set_admin(&client.address);
+
+    let create_call_ballot = |function: Symbol, args: Vec<Val>| {
+        let mut params = ballot_params(&env, &owner, BallotCategory::GenericCall);
+        params.payload = BallotPayload::GenericCall(ContractCall {
+            contract: governed_id.clone(),
+            function,
+            args,
+        });
+        client.create_ballot(&params)
+    };
+
+    // the call is not executed until the ballot is accepted
+    let ballot_id = create_call_ballot(symbol_short!("set_value"), vec![&env, 42u32.into_val(&env)]);
+    assert_eq!(governed.value(), 0);
+    assert_eq!(client.get_ballot(&ballot_id).execution, ExecutionStatus::None);
+
+    client.vote(&ballot_id, &5, &0, &VoteRationale::None);
+    assert_eq!(governed.value(), 42);
+    let ballot = client.get_ballot(&ballot_id);
+    assert_eq!(ballot.status, BallotStatus::Accepted);
+    assert_eq!(ballot.execution, ExecutionStatus::Succeeded);
+
+    // the ballot is executed only once
+    let res = client.try_vote(&ballot_id, &5, &0, &VoteRationale::None);
+    assert_eq!(res, Err(Ok(contract_error(Error::BallotClosed))));
+
+    // failed invocation doesn't revert the decision
+    let ballot_id = create_call_ballot(symbol_short!("missing"), Vec::new(&env));
+    client.vote(&ballot_id, &5, &0, &VoteRationale::None);
+    let ballot = client.get_ballot(&ballot_id);
+    assert_eq!(ballot.status, BallotStatus::Accepted);
+    assert_eq!(ballot.execution, ExecutionStatus::Failed);
+    assert_eq!(governed.value(), 42);
+
+    // the DAO contract itself can't be invoked
+    let mut params = ballot_params(&env, &owner, BallotCategory::GenericCall);
+    params.payload = BallotPayload::GenericCall(ContractCall {
+        contract: client.address.clone(),
+        function: symbol_short!("unlock"),
+        args: Vec::new(&env),
+    });
+    let res = client.try_create_ballot(&params);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidBallotParams))));
+
+    // neither can the DAO token
+    params.payload = BallotPayload::GenericCall(ContractCall {
+        contract: config.token.clone(),
+        function: symbol_short!("transfer"),
+        args: (client.address.clone(), owner.clone(), 1_000_0000000i128).into_val(&env),
+    });
+    let res = client.try_create_ballot(&params);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidBallotParams))));
+}
+
+#[test]
+fn test_revenue_deposit() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let subscriptions = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&subscriptions, &1_000_0000000);
+
+    client.deposit_revenue(&subscriptions, &300_0000000, &symbol_short!("subs"));
+    client.deposit_revenue(&subscriptions, &200_0000000, &symbol_short!("subs"));
+
+    assert_eq!(client.get_revenue_balance(), 500_0000000);
+    assert_eq!(TokenClient::new(&env, &config.token).balance(&subscriptions), 500_0000000);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (REFLECTOR, symbol_short!("dao"), symbol_short!("revenue")).into_val(&env));
+    let (from, amount, source): (Address, i128, Symbol) = data.into_val(&env);
+    assert_eq!((from, amount, source), (subscriptions.clone(), 200_0000000, symbol_short!("subs")));
+
+    env.as_contract(&client.address, || {
+        // revenue is tracked separately from the treasury
+        assert_eq!(env.get_dao_balance(), config.amount);
+    });
+
+    let res = client.try_deposit_revenue(&subscriptions, &0, &symbol_short!("subs"));
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidAmount))));
+}
+
+#[test]
+fn test_revenue_distribution() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let subscriptions = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&subscriptions, &1_000_0000000);
+
+    let developer = Address::generate(&env);
+    let operators = vec![&env, Address::generate(&env), Address::generate(&env)];
+    client.set_developers(&Map::from_array(&env, [(developer.clone(), 10000)]));
+    client.set_operators(&operators);
+
+    client.deposit_revenue(&subscriptions, &900_0000000, &symbol_short!("subs"));
+
+    env.ledger().set_timestamp(UNLOCK_PERIOD as u64);
+    client.unlock(&developer);
+
+    // 40% of the revenue goes to operators, 10% to the developer
+    let emission_per_operator = config.amount * OPERATORS_SHARE / PERCENTAGE_FACTOR / 2;
+    let emission_developer = config.amount * DEVELOPERS_SHARE / PERCENTAGE_FACTOR;
+    for operator in operators.iter() {
+        assert_eq!(client.available(&operator), emission_per_operator + 180_0000000);
+    }
+    assert_eq!(client.available(&developer), emission_developer + 90_0000000);
+    assert_eq!(client.get_revenue_balance(), 450_0000000);
+
+    let (_, _, data) = env.events().all().last().unwrap();
+    let (emission, revenue): (i128, i128) = data.into_val(&env);
+    assert_eq!(emission, emission_per_operator * 2 + emission_developer);
+    assert_eq!(revenue, 450_0000000);
+
+    // only the revenue accrued since the last unlock is distributed
+    client.deposit_revenue(&subscriptions, &100_0000000, &symbol_short!("subs"));
+    client.set_revenue_shares(&RevenueShares { operators: 6000, developers: 4000 });
+    env.ledger().set_timestamp(UNLOCK_PERIOD as u64 * 2);
+    client.unlock(&developer);
+    assert_eq!(client.get_revenue_balance(), 450_0000000);
+
+    let res = client.try_set_revenue_shares(&RevenueShares { operators: 6000, developers: 4001 });
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidRevenueShares))));
+
+    // the undistributed remainder can be moved to the treasury, accrued revenue is reserved for the next unlock
+    StellarAssetClient::new(&env, &config.token).mint(&subscriptions, &100_0000000);
+    client.deposit_revenue(&subscriptions, &100_0000000, &symbol_short!("subs"));
+    let res = client.try_sweep_revenue(&(450_0000000 + 1));
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidAmount))));
+    let dao_balance = client.solvency_report().dao_balance;
+    client.sweep_revenue(&450_0000000);
+    assert_eq!(client.get_revenue_balance(), 100_0000000);
+    assert_eq!(client.solvency_report().dao_balance, dao_balance + 450_0000000);
+    assert_eq!(client.solvency_report().surplus, 0);
+}
+
+#[test]
+fn test_fund() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let donor = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&donor, &1_000_0000000);
+
+    client.fund(&donor, &300_0000000);
+    client.fund(&donor, &200_0000000);
+
+    assert_eq!(client.get_donations(&donor), 500_0000000);
+    assert_eq!(client.get_donations(&Address::generate(&env)), 0);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (REFLECTOR, symbol_short!("dao"), symbol_short!("funded")).into_val(&env));
+    let (from, amount): (Address, i128) = data.into_val(&env);
+    assert_eq!((from, amount), (donor.clone(), 200_0000000));
+
+    env.as_contract(&client.address, || {
+        assert_eq!(env.get_dao_balance(), config.amount + 500_0000000);
+        // donor totals are kept in persistent storage
+        assert!(env.storage().persistent().has(&("donations", donor.clone())));
+        assert!(!env.storage().instance().has(&("donations", donor.clone())));
+    });
+
+    let res = client.try_fund(&donor, &-1);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidAmount))));
+}
+
+#[test]
+fn test_solvency_report() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let owner = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&owner, &500_000_0000000);
+
+    let report = client.solvency_report();
+    assert_eq!(report.token_balance, config.amount);
+    assert_eq!(report.dao_balance, config.amount);
+    assert_eq!(report.surplus, 0);
+
+    // deposits are escrowed until the ballot is closed
+    let ballot_id = client.create_ballot(&ballot_params(&env, &owner, BallotCategory::AddAsset));
+    assert_eq!(client.solvency_report().escrowed, 5_000_0000000);
+    client.vote(&ballot_id, &1, &2, &VoteRationale::None);
+    assert_eq!(client.solvency_report().escrowed, 3_750_0000000);
+    client.retract_ballot(&ballot_id);
+    assert_eq!(client.solvency_report().escrowed, 0);
+
+    // unlocked tokens become liabilities
+    env.ledger().set_timestamp(UNLOCK_PERIOD as u64);
+    let developer = Address::generate(&env);
+    client.set_developers(&Map::from_array(&env, [(developer.clone(), 10000)]));
+    client.set_operators(&vec![&env, Address::generate(&env)]);
+    client.unlock(&developer);
+    let report = client.solvency_report();
+    assert!(report.liabilities > 0);
+    assert_eq!(report.dao_balance + report.liabilities, report.token_balance);
+    assert_eq!(report.surplus, 0);
+
+    // direct transfers are not accounted
+    TokenClient::new(&env, &config.token).transfer(&owner, &client.address, &1_000_0000000);
+    assert_eq!(client.solvency_report().surplus, 1_000_0000000);
+
+    let dao_balance = client.solvency_report().dao_balance;
+    assert_eq!(client.sync_surplus(), 1_000_0000000);
+    let report = client.solvency_report();
+    assert_eq!(report.surplus, 0);
+    assert_eq!(report.dao_balance, dao_balance + 1_000_0000000);
+
+    // nothing to sync
+    assert_eq!(client.sync_surplus(), 0);
+}
+
+#[test]
+fn test_liabilities() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let subscriptions = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&subscriptions, &1_000_0000000);
+
+    let developer = Address::generate(&env);
+    let operators = vec![&env, Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    client.set_developers(&Map::from_array(&env, [(developer.clone(), 10000)]));
+    client.set_operators(&operators);
+    let mut claimants = operators.clone();
+    claimants.push_back(developer.clone());
+
+    let total_available = || claimants.iter().map(|claimant| client.available(&claimant)).sum::<i128>();
+
+    assert_eq!(client.get_liabilities(), 0);
+
+    client.deposit_revenue(&subscriptions, &1_000_0000000, &symbol_short!("subs"));
+    env.ledger().set_timestamp(UNLOCK_PERIOD as u64);
+    client.unlock(&developer);
+    assert_eq!(client.get_liabilities(), total_available());
+
+    env.ledger().set_timestamp(UNLOCK_PERIOD as u64 * 2);
+    client.unlock(&developer);
+    assert_eq!(client.get_liabilities(), total_available());
+
+    // claims reduce the liabilities
+    let operator = operators.first().unwrap();
+    client.claim(&operator, &operator, &(client.available(&operator) / 2));
+    client.claim(&developer, &developer, &client.available(&developer));
+    assert_eq!(client.get_liabilities(), total_available());
+    assert_eq!(client.solvency_report().surplus, 0);
+}
+
+#[test]
+fn test_vesting() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let beneficiary = Address::generate(&env);
+    let month = 30 * 24 * 3600u64;
+    let params = VestingParams {
+        beneficiary: beneficiary.clone(),
+        total: 2_000_000_0000000,
+        start: 1000,
+        cliff: 6 * month,
+        duration: 24 * month,
+        revocable: true,
+    };
+    client.create_vesting(&params);
+
+    // tokens are reserved from the treasury
+    let report = client.solvency_report();
+    assert_eq!(report.dao_balance, config.amount - params.total);
+    assert_eq!(report.vesting, params.total);
+    assert_eq!(report.surplus, 0);
+
+    let res = client.try_create_vesting(&params);
+    assert_eq!(res, Err(Ok(contract_error(Error::VestingExists))));
+
+    // nothing is vested before the cliff
+    env.ledger().set_timestamp(1000 + 6 * month - 1);
+    assert_eq!(client.available(&beneficiary), 0);
+    let res = client.try_claim(&beneficiary, &beneficiary, &1);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidAmount))));
+
+    // a quarter is vested after the cliff
+    env.ledger().set_timestamp(1000 + 6 * month);
+    assert_eq!(client.available(&beneficiary), 500_000_0000000);
+    client.claim(&beneficiary, &beneficiary, &200_000_0000000);
+    assert_eq!(client.available(&beneficiary), 300_000_0000000);
+    assert_eq!(client.get_vesting(&beneficiary).released, 500_000_0000000);
+    assert_eq!(TokenClient::new(&env, &config.token).balance(&beneficiary), 200_000_0000000);
+    // schedules are kept in persistent storage
+    env.as_contract(&client.address, || {
+        assert!(env.storage().persistent().get_ttl(&("vesting", beneficiary.clone())) > 0);
+    });
+
+    // revocation returns unvested tokens to the treasury
+    env.ledger().set_timestamp(1000 + 12 * month);
+    client.revoke_vesting(&beneficiary);
+    assert_eq!(client.available(&beneficiary), 800_000_0000000);
+    let report = client.solvency_report();
+    assert_eq!(report.dao_balance, config.amount - 1_000_000_0000000);
+    assert_eq!(report.vesting, 0);
+    assert_eq!(report.surplus, 0);
+    let res = client.try_get_vesting(&beneficiary);
+    assert_eq!(res, Err(Ok(contract_error(Error::VestingNotFound))));
+
+    // irrevocable schedules are fully vested after the duration
+    let beneficiary = Address::generate(&env);
+    client.create_vesting(&VestingParams {
+        beneficiary: beneficiary.clone(),
+        revocable: false,
+        ..params
+    });
+    let res = client.try_revoke_vesting(&beneficiary);
+    assert_eq!(res, Err(Ok(contract_error(Error::VestingIrrevocable))));
+    env.ledger().set_timestamp(1000 + 48 * month);
+    assert_eq!(client.available(&beneficiary), 2_000_000_0000000);
+}
+
+#[test]
+fn test_emission_schedule() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let developer = Address::generate(&env);
+    let operators = vec![&env, Address::generate(&env)];
+    client.set_developers(&Map::from_array(&env, [(developer.clone(), 10000)]));
+    client.set_operators(&operators);
+
+    let unlock = |epoch: u64| {
+        env.ledger().set_timestamp(UNLOCK_PERIOD as u64 * epoch);
+        client.unlock(&developer);
+        let (_, _, data) = env.events().all().last().unwrap();
+        let (emission, _): (i128, i128) = data.into_val(&env);
+        emission
+    };
+
+    // decay emission is projected from the current balance
+    let first = config.amount * 15 / PERCENTAGE_FACTOR;
+    let second = (config.amount - first) * 15 / PERCENTAGE_FACTOR;
+    assert_eq!(client.preview_emission(&0), first);
+    assert_eq!(client.preview_emission(&1), second);
+    assert_eq!(unlock(1), first);
+    assert_eq!(unlock(2), second);
+
+    let res = client.try_preview_emission(&1);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidEpoch))));
+
+    client.set_emission_schedule(&EmissionSchedule::Fixed(1_000_0000000));
+    assert_eq!(client.preview_emission(&10), 1_000_0000000);
+    assert_eq!(unlock(3), 1_000_0000000);
+    assert_eq!(client.available(&developer), first / 5 + second / 5 + 200_0000000);
+
+    // the amount is halved at epochs 4 and 6
+    client.set_emission_schedule(&EmissionSchedule::Halving(HalvingParams {
+        amount: 1_000_0000000,
+        epochs: vec![&env, 4, 6],
+    }));
+    assert_eq!(client.preview_emission(&3), 1_000_0000000);
+    assert_eq!(client.preview_emission(&4), 500_0000000);
+    assert_eq!(client.preview_emission(&6), 250_0000000);
+    assert_eq!(unlock(4), 1_000_0000000);
+    assert_eq!(unlock(5), 500_0000000);
+
+    let res = client.try_set_emission_schedule(&EmissionSchedule::Halving(HalvingParams {
+        amount: 1_000_0000000,
+        epochs: vec![&env, 6, 4],
+    }));
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidEmissionSchedule))));
+
+    let res = client.try_set_emission_schedule(&EmissionSchedule::Decay(0));
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidEmissionSchedule))));
+}
+
+#[test]
+fn test_permissionless_unlock() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let keeper = Address::generate(&env);
+    env.ledger().set_timestamp(UNLOCK_PERIOD as u64);
+
+    // recipients should be set before the unlock
+    let res = client.try_unlock(&keeper);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidOperators))));
+
+    let operators = vec![&env, Address::generate(&env), Address::generate(&env)];
+    client.set_operators(&operators);
+    let res = client.try_unlock(&keeper);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidDevelopers))));
+
+    let developer = Address::generate(&env);
+    client.set_developers(&Map::from_array(&env, [(developer.clone(), 10000)]));
+    client.set_keeper_reward(&10_0000000);
+
+    // anyone can trigger the unlock and receive the keeper reward
+    client.unlock(&keeper);
+    env.as_contract(&client.address, || {
+        assert_eq!(env.get_available_balance(&keeper), 10_0000000);
+        let emission = config.amount * 15 / PERCENTAGE_FACTOR;
+        assert_eq!(env.get_dao_balance(), config.amount - emission - 10_0000000);
+    });
+    assert!(client.available(&developer) > 0);
+    assert_eq!(client.solvency_report().surplus, 0);
+
+    // the period should elapse before the next unlock
+    let res = client.try_unlock(&keeper);
+    assert_eq!(res, Err(Ok(contract_error(Error::UnlockUnavailable))));
+
+    let res = client.try_set_operators(&vec![&env, developer.clone(), developer.clone()]);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidOperators))));
+}
+
+#[test]
+fn test_developer_splits() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.set_operators(&vec![&env, Address::generate(&env)]);
+
+    // splits should sum up to 100%
+    let res = client.try_set_developers(&Map::from_array(&env, [(first.clone(), 7000), (second.clone(), 2000)]));
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidDevelopers))));
+    let res = client.try_set_developers(&Map::from_array(&env, [(first.clone(), 10000), (second.clone(), 0)]));
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidDevelopers))));
+    let res = client.try_set_developers(&Map::new(&env));
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidDevelopers))));
+
+    client.set_developers(&Map::from_array(&env, [(first.clone(), 7000), (second.clone(), 3000)]));
+
+    env.ledger().set_timestamp(UNLOCK_PERIOD as u64);
+    client.unlock(&first);
+
+    let developers_unlocked = config.amount * DEVELOPERS_SHARE / PERCENTAGE_FACTOR;
+    assert_eq!(client.available(&first), developers_unlocked * 7 / 10);
+    assert_eq!(client.available(&second), developers_unlocked * 3 / 10);
+    assert_eq!(client.solvency_report().surplus, 0);
+}
+
+#[test]
+fn test_claim_delegation() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let developer = Address::generate(&env);
+    let operator = Address::generate(&env);
+    client.set_developers(&Map::from_array(&env, [(developer.clone(), 10000)]));
+    client.set_operators(&vec![&env, operator.clone()]);
+
+    env.ledger().set_timestamp(UNLOCK_PERIOD as u64);
+    client.unlock(&operator);
+
+    let payout = Address::generate(&env);
+    let claim_operator = Address::generate(&env);
+    let amount = client.available(&operator) / 4;
+
+    // claim operator is not delegated yet
+    let res = client.try_claim_for(&claim_operator, &operator, &amount);
+    assert_eq!(res, Err(Ok(contract_error(Error::Unauthorized))));
+
+    client.set_claim_operator(&operator, &claim_operator);
+    assert_eq!(client.get_claim_operator(&operator), Some(claim_operator.clone()));
+
+    // payout address is required for delegated claims
+    let res = client.try_claim_for(&claim_operator, &operator, &amount);
+    assert_eq!(res, Err(Ok(contract_error(Error::PayoutNotSet))));
+
+    client.set_payout_address(&operator, &payout);
+    assert_eq!(client.get_payout_address(&operator), Some(payout.clone()));
+    env.as_contract(&client.address, || {
+        assert!(env.storage().persistent().has(&("payout_address", operator.clone())));
+        assert!(env.storage().persistent().has(&("claim_operator", operator.clone())));
+    });
+
+    let available = client.available(&operator);
+    client.claim_for(&claim_operator, &operator, &amount);
+    assert_eq!(TokenClient::new(&env, &config.token).balance(&payout), amount);
+    assert_eq!(client.available(&operator), available - amount);
+
+    // other accounts can't claim on behalf of the claimant
+    let res = client.try_claim_for(&Address::generate(&env), &operator, &amount);
+    assert_eq!(res, Err(Ok(contract_error(Error::Unauthorized))));
+
+    client.revoke_claim_operator(&operator);
+    assert_eq!(client.get_claim_operator(&operator), None);
+    let res = client.try_revoke_claim_operator(&operator);
+    assert_eq!(res, Err(Ok(contract_error(Error::ClaimOperatorNotSet))));
+    let res = client.try_claim_for(&claim_operator, &operator, &amount);
+    assert_eq!(res, Err(Ok(contract_error(Error::Unauthorized))));
+
+    client.revoke_payout_address(&operator);
+    assert_eq!(client.get_payout_address(&operator), None);
+    let res = client.try_revoke_payout_address(&operator);
+    assert_eq!(res, Err(Ok(contract_error(Error::PayoutNotSet))));
+    assert_eq!(client.solvency_report().surplus, 0);
+}
+
+#[test]
+fn test_push_distribution() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let developer = Address::generate(&env);
+    let pushed = Address::generate(&env);
+    let failing = Address::generate(&env);
+    client.set_developers(&Map::from_array(&env, [(developer.clone(), 10000)]));
+    client.set_operators(&vec![&env, pushed.clone(), failing.clone()]);
+
+    let res = client.try_set_push_mode(&Address::generate(&env), &true);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidRecipient))));
+
+    client.set_push_mode(&pushed, &true);
+    client.set_push_mode(&failing, &true);
+    assert!(client.get_push_mode(&pushed));
+    assert!(!client.get_push_mode(&developer));
+
+    // deauthorized recipient can't receive tokens, the share is credited instead
+    StellarAssetClient::new(&env, &config.token).set_authorized(&failing, &false);
+
+    env.ledger().set_timestamp(UNLOCK_PERIOD as u64);
+    client.unlock(&developer);
+
+    let token = TokenClient::new(&env, &config.token);
+    let per_operator = config.amount * OPERATORS_SHARE / PERCENTAGE_FACTOR / 2;
+    assert_eq!(token.balance(&pushed), per_operator);
+    assert_eq!(client.available(&pushed), 0);
+    assert_eq!(token.balance(&failing), 0);
+    assert_eq!(client.available(&failing), per_operator);
+    assert_eq!(client.available(&developer), config.amount * DEVELOPERS_SHARE / PERCENTAGE_FACTOR);
+    assert_eq!(client.get_liabilities(), per_operator + client.available(&developer));
+    assert_eq!(client.solvency_report().surplus, 0);
+
+    // push mode can be disabled
+    client.set_push_mode(&pushed, &false);
+    env.ledger().set_timestamp(UNLOCK_PERIOD as u64 * 2);
+    client.unlock(&developer);
+    assert_eq!(token.balance(&pushed), per_operator);
+    assert!(client.available(&pushed) > 0);
+}
+
+#[test]
+fn test_pause() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let developer = Address::generate(&env);
+    client.set_developers(&Map::from_array(&env, [(developer.clone(), 10000)]));
+    client.set_operators(&vec![&env, Address::generate(&env)]);
+
+    let beneficiary = Address::generate(&env);
+    client.create_vesting(&VestingParams {
+        beneficiary: beneficiary.clone(),
+        total: 1_000_0000000,
+        start: 0,
+        cliff: 0,
+        duration: UNLOCK_PERIOD as u64 * 10,
+        revocable: true,
+    });
+
+    let guardian = Address::generate(&env);
+    client.set_role(&Role::Guardian, &guardian);
+    for group in [PauseGroup::Ballots, PauseGroup::Voting, PauseGroup::Unlock, PauseGroup::Claims] {
+        client.pause(&group);
+        assert!(client.is_paused(&group));
+    }
+
+    env.ledger().set_timestamp(UNLOCK_PERIOD as u64);
+    let res = client.try_unlock(&developer);
+    assert_eq!(res, Err(Ok(contract_error(Error::Paused))));
+    let res = client.try_claim(&developer, &developer, &1);
+    assert_eq!(res, Err(Ok(contract_error(Error::Paused))));
+    let initiator = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&initiator, &1_000_000_0000000);
+    let res = client.try_create_ballot(&ballot_params(&env, &initiator, BallotCategory::General));
+    assert_eq!(res, Err(Ok(contract_error(Error::Paused))));
+    let res = client.try_vote(&1, &3, &0, &VoteRationale::None);
+    assert_eq!(res, Err(Ok(contract_error(Error::Paused))));
+    let res = client.try_retract_ballot(&1);
+    assert_eq!(res, Err(Ok(contract_error(Error::Paused))));
+
+    // vesting can still be revoked while claims are paused
+    client.revoke_vesting(&beneficiary);
+    let res = client.try_claim(&beneficiary, &beneficiary, &1);
+    assert_eq!(res, Err(Ok(contract_error(Error::Paused))));
+
+    // unpausing requires admin permissions, the guardian authorization is not enough
+    env.mock_auths(&[MockAuth {
+        address: &guardian,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "unpause",
+            args: (PauseGroup::Unlock,).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_unpause(&PauseGroup::Unlock).is_err());
+    env.mock_all_auths();
+
+    client.unpause(&PauseGroup::Unlock);
+    client.unpause(&PauseGroup::Ballots);
+    assert!(!client.is_paused(&PauseGroup::Unlock));
+    client.unlock(&developer);
+    client.create_ballot(&ballot_params(&env, &initiator, BallotCategory::General));
+
+    // other groups stay paused
+    let res = client.try_claim(&developer, &developer, &1);
+    assert_eq!(res, Err(Ok(contract_error(Error::Paused))));
+    client.unpause(&PauseGroup::Claims);
+    client.claim(&developer, &developer, &1);
+}
+
+#[test]
+fn test_roles() {
+    let (env, client, config) = init_contract_with_admin();
+
+    // unassigned roles fall back to the admin
+    assert_eq!(client.get_role(&Role::Treasurer), Some(config.admin.clone()));
+    client.set_keeper_reward(&1_0000000);
+    assert_eq!(env.auths()[0].0, config.admin);
+
+    let governor = Address::generate(&env);
+    let recorder = Address::generate(&env);
+    let treasurer = Address::generate(&env);
+    client.set_role(&Role::Governor, &governor);
+    client.set_role(&Role::VoteRecorder, &recorder);
+    client.set_role(&Role::Treasurer, &treasurer);
+    assert_eq!(client.get_role(&Role::Governor), Some(governor.clone()));
+
+    client.set_keeper_reward(&2_0000000);
+    assert_eq!(env.auths()[0].0, governor);
+
+    let developer = Address::generate(&env);
+    client.set_developers(&Map::from_array(&env, [(developer.clone(), 10000)]));
+    assert_eq!(env.auths()[0].0, governor);
+    client.set_operators(&vec![&env, Address::generate(&env)]);
+    assert_eq!(env.auths()[0].0, governor);
+
+    client.sync_surplus();
+    assert_eq!(env.auths()[0].0, treasurer);
+
+    let initiator = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&initiator, &1_000_000_0000000);
+    let ballot_id = client.create_ballot(&ballot_params(&env, &initiator, BallotCategory::General));
+    client.vote(&ballot_id, &3, &0, &VoteRationale::None);
+    assert_eq!(env.auths()[0].0, recorder);
+
+    // the vote recorder can't change parameters
+    env.mock_auths(&[MockAuth {
+        address: &recorder,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "set_keeper_reward",
+            args: (3_0000000i128,).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_set_keeper_reward(&3_0000000).is_err());
+    env.mock_all_auths();
+
+    // revoked role falls back to the admin
+    client.revoke_role(&Role::Governor);
+    assert_eq!(client.get_role(&Role::Governor), Some(config.admin.clone()));
+    client.set_keeper_reward(&3_0000000);
+    assert_eq!(env.auths()[0].0, config.admin);
+}
+
+#[test]
+fn test_signer_set() {
+    let (env, client, _) = init_contract_with_admin();
+
+    let signers = vec![&env, Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    let invalid = SignerSet { signers: signers.clone(), threshold: 4 };
+    let res = client.try_set_signers(&invalid);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidSigners))));
+    let mut duplicates = signers.clone();
+    duplicates.push_back(signers.first().unwrap());
+    let res = client.try_set_signers(&SignerSet { signers: duplicates, threshold: 2 });
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidSigners))));
+
+    let signer_set = SignerSet { signers: signers.clone(), threshold: 2 };
+    client.set_signers(&signer_set);
+    assert_eq!(client.get_signers(), Some(signer_set));
+
+    // admin actions require approvals of the signers
+    let res = client.try_set_keeper_reward(&1_0000000);
+    assert_eq!(res, Err(Ok(contract_error(Error::Unauthorized))));
+
+    let action = action_hash(&env, "set_keeper_reward", (1_0000000i128,).into_val(&env));
+    let res = client.try_approve(&Address::generate(&env), &action);
+    assert_eq!(res, Err(Ok(contract_error(Error::Unauthorized))));
+
+    client.approve(&signers.get(0).unwrap(), &action);
+    client.approve(&signers.get(0).unwrap(), &action);
+    let res = client.try_set_keeper_reward(&1_0000000);
+    assert_eq!(res, Err(Ok(contract_error(Error::Unauthorized))));
+
+    client.approve(&signers.get(2).unwrap(), &action);
+    assert_eq!(client.get_approvals(&action).len(), 2);
+    client.set_keeper_reward(&1_0000000);
+
+    // approvals are consumed
+    assert_eq!(client.get_approvals(&action).len(), 0);
+    let res = client.try_set_keeper_reward(&1_0000000);
+    assert_eq!(res, Err(Ok(contract_error(Error::Unauthorized))));
+
+    // approvals are bound to the arguments
+    client.approve(&signers.get(0).unwrap(), &action);
+    client.approve(&signers.get(1).unwrap(), &action);
+    let res = client.try_set_keeper_reward(&2_0000000);
+    assert_eq!(res, Err(Ok(contract_error(Error::Unauthorized))));
+
+    // approvals can be revoked before execution
+    let action = action_hash(&env, "set_keeper_reward", (2_0000000i128,).into_val(&env));
+    client.approve(&signers.get(0).unwrap(), &action);
+    client.approve(&signers.get(1).unwrap(), &action);
+    client.revoke_approval(&signers.get(1).unwrap(), &action);
+    assert_eq!(client.get_approvals(&action), vec![&env, signers.get(0).unwrap()]);
+    let res = client.try_revoke_approval(&signers.get(1).unwrap(), &action);
+    assert_eq!(res, Err(Ok(contract_error(Error::ApprovalNotFound))));
+    let res = client.try_set_keeper_reward(&2_0000000);
+    assert_eq!(res, Err(Ok(contract_error(Error::Unauthorized))));
+
+    // abandoned approvals expire
+    env.ledger().set_sequence_number(env.ledger().sequence() + 17280 * 30 + 1);
+    assert!(client.get_approvals(&action).is_empty());
+
+    // unassigned roles have no holder address once the signer set is configured
+    assert_eq!(client.get_role(&Role::Governor), None);
+
+    // assigned governor still requires approvals
+    let governor = Address::generate(&env);
+    let action = action_hash(&env, "set_role", (Role::Governor, governor.clone()).into_val(&env));
+    client.approve(&signers.get(1).unwrap(), &action);
+    client.approve(&signers.get(2).unwrap(), &action);
+    client.set_role(&Role::Governor, &governor);
+    assert_eq!(client.get_role(&Role::Governor), Some(governor.clone()));
+    let res = client.try_set_keeper_reward(&2_0000000);
+    assert_eq!(res, Err(Ok(contract_error(Error::Unauthorized))));
+    let action = action_hash(&env, "set_keeper_reward", (2_0000000i128,).into_val(&env));
+    client.approve(&signers.get(0).unwrap(), &action);
+    client.approve(&signers.get(2).unwrap(), &action);
+    client.set_keeper_reward(&2_0000000);
+    assert_eq!(env.auths()[0].0, governor);
+
+    // assigned guardian doesn't require approvals
+    let guardian = Address::generate(&env);
+    let action = action_hash(&env, "set_role", (Role::Guardian, guardian.clone()).into_val(&env));
+    client.approve(&signers.get(1).unwrap(), &action);
+    client.approve(&signers.get(2).unwrap(), &action);
+    client.set_role(&Role::Guardian, &guardian);
+    client.pause(&PauseGroup::Claims);
+    assert_eq!(env.auths()[0].0, guardian);
+
+    // signer set changes require the threshold as well
+    let new_set = SignerSet { signers: vec![&env, signers.get(0).unwrap()], threshold: 1 };
+    let res = client.try_set_signers(&new_set);
+    assert_eq!(res, Err(Ok(contract_error(Error::Unauthorized))));
+    let action = action_hash(&env, "set_signers", (new_set.clone(),).into_val(&env));
+    client.approve(&signers.get(1).unwrap(), &action);
+    client.approve(&signers.get(2).unwrap(), &action);
+    client.set_signers(&new_set);
+    assert_eq!(client.get_signers(), Some(new_set));
+}
+
+#[test]
+fn test_timelock() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let res = client.try_set_timelock_delay(&(86400 * 31));
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidTimelockDelay))));
+
+    // zero delay applies the delay change immediately
+    client.set_timelock_delay(&86400);
+    assert_eq!(client.get_timelock_delay(), 86400);
+    assert_eq!(client.get_queued_changes().len(), 0);
+
+    // invalid changes are rejected before queueing
+    let res = client.try_set_revenue_shares(&RevenueShares { operators: 9000, developers: 2000 });
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidRevenueShares))));
+
+    env.ledger().set_timestamp(1000);
+    let mut deposit_params = Map::new(&env);
+    for category in BallotCategory::iterator() {
+        deposit_params.set(category, 1_000_0000000);
+    }
+    client.set_deposit(&deposit_params);
+    let oracle = Address::generate(&env);
+    client.set_oracle(&oracle);
+
+    let queued = client.get_queued_changes();
+    assert_eq!(queued.len(), 2);
+    let deposit_change = queued.get(0).unwrap();
+    assert_eq!(deposit_change.change, ParamChange::Deposit(deposit_params));
+    assert_eq!(deposit_change.eta, 1000 + 86400);
+
+    // ballot deposits are not affected until the change is executed
+    let initiator = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&initiator, &1_000_000_0000000);
+    client.create_ballot(&ballot_params(&env, &initiator, BallotCategory::General));
+    let token = TokenClient::new(&env, &config.token);
+    let general_deposit = config.deposit_params.get(BallotCategory::General).unwrap();
+    assert_eq!(token.balance(&initiator), 1_000_000_0000000 - general_deposit);
+
+    let res = client.try_execute_change(&deposit_change.id);
+    assert_eq!(res, Err(Ok(contract_error(Error::TimelockActive))));
+
+    // the guardian can cancel queued changes
+    let guardian = Address::generate(&env);
+    client.set_role(&Role::Guardian, &guardian);
+    assert_eq!(client.get_role(&Role::Guardian), Some(config.admin.clone()));
+    let oracle_change = queued.get(1).unwrap();
+    client.cancel_change(&oracle_change.id);
+    let res = client.try_execute_change(&oracle_change.id);
+    assert_eq!(res, Err(Ok(contract_error(Error::ChangeNotFound))));
+
+    env.ledger().set_timestamp(1000 + 86400);
+    client.execute_change(&deposit_change.id);
+    client.create_ballot(&ballot_params(&env, &initiator, BallotCategory::General));
+    assert_eq!(token.balance(&initiator), 1_000_000_0000000 - general_deposit - 1_000_0000000);
+
+    // queued role assignment
+    let role_change = client.get_queued_changes().first().unwrap();
+    assert_eq!(role_change.change, ParamChange::SetRole(Role::Guardian, guardian.clone()));
+    client.execute_change(&role_change.id);
+    assert_eq!(client.get_role(&Role::Guardian), Some(guardian.clone()));
+    assert_eq!(client.get_queued_changes().len(), 0);
+
+    // the guardian can't cancel its own replacement or a timelock delay update
+    client.set_role(&Role::Guardian, &Address::generate(&env));
+    client.set_timelock_delay(&0);
+    for change in client.get_queued_changes().iter() {
+        client.cancel_change(&change.id);
+        assert_eq!(env.auths()[0].0, config.admin);
+    }
+    client.set_oracle(&oracle);
+    let oracle_change = client.get_queued_changes().first().unwrap();
+    client.cancel_change(&oracle_change.id);
+    assert_eq!(env.auths()[0].0, guardian);
+    assert_eq!(client.get_queued_changes().len(), 0);
+
+    // unlock recipients are governed like other parameters
+    let developer = Address::generate(&env);
+    let developers = Map::from_array(&env, [(developer.clone(), 10000)]);
+    client.set_developers(&developers);
+    let res = client.try_set_operators(&Vec::new(&env));
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidOperators))));
+    client.set_operators(&vec![&env, developer.clone()]);
+    let queued = client.get_queued_changes();
+    assert_eq!(queued.get(0).unwrap().change, ParamChange::Developers(developers));
+    env.ledger().set_timestamp(UNLOCK_PERIOD as u64);
+    let res = client.try_unlock(&developer);
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidOperators))));
+    client.execute_change(&queued.get(0).unwrap().id);
+    client.execute_change(&queued.get(1).unwrap().id);
+    client.unlock(&developer);
+}
+
+#[test]
+fn test_decay_emission_escrow() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let developer = Address::generate(&env);
+    client.set_developers(&Map::from_array(&env, [(developer.clone(), 10000)]));
+    client.set_operators(&vec![&env, Address::generate(&env)]);
+
+    // open ballot deposits don't raise the decay emission
+    let initiator = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&initiator, &1_000_000_0000000);
+    client.create_ballot(&ballot_params(&env, &initiator, BallotCategory::General));
+    let deposit = config.deposit_params.get(BallotCategory::General).unwrap();
+    assert_eq!(client.solvency_report().dao_balance, config.amount + deposit);
+
+    let first = config.amount * 15 / PERCENTAGE_FACTOR;
+    let second = (config.amount - first) * 15 / PERCENTAGE_FACTOR;
+    assert_eq!(client.preview_emission(&0), first);
+    assert_eq!(client.preview_emission(&1), second);
+
+    env.ledger().set_timestamp(UNLOCK_PERIOD as u64);
+    client.unlock(&developer);
+    let (_, _, data) = env.events().all().last().unwrap();
+    let (emission, _): (i128, i128) = data.into_val(&env);
+    assert_eq!(emission, first);
+    assert_eq!(client.solvency_report().dao_balance, config.amount + deposit - first);
+}
+
+#[test]
+fn test_emission_cap() {
+    let (env, client, config) = init_contract_with_admin();
+
+    let developer = Address::generate(&env);
+    client.set_developers(&Map::from_array(&env, [(developer.clone(), 10000)]));
+    client.set_operators(&vec![&env, Address::generate(&env)]);
+    client.set_keeper_reward(&1_0000000);
+
+    // decay projection is bounded
+    let res = client.try_preview_emission(&(MAX_PREVIEW_EPOCHS + 1));
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidEpoch))));
+
+    // fixed emission exceeding the treasury
+    client.set_emission_schedule(&EmissionSchedule::Fixed(config.amount * 2));
+    assert_eq!(client.preview_emission(&1_000_000), 0);
+
+    let initiator = Address::generate(&env);
+    StellarAssetClient::new(&env, &config.token).mint(&initiator, &1_000_000_0000000);
+    let ballot_id = client.create_ballot(&ballot_params(&env, &initiator, BallotCategory::General));
+    let deposit = config.deposit_params.get(BallotCategory::General).unwrap();
+    assert_eq!(client.preview_emission(&0), config.amount);
+
+    // escrowed deposits are neither emitted nor paid as the keeper reward
+    env.ledger().set_timestamp(UNLOCK_PERIOD as u64);
+    client.unlock(&developer);
+    assert_eq!(client.available(&developer), config.amount * DEVELOPERS_SHARE / 15);
+    let report = client.solvency_report();
+    assert_eq!(report.dao_balance, deposit);
+    assert_eq!(report.escrowed, deposit);
+
+    // vesting can't reserve escrowed deposits
+    let res = client.try_create_vesting(&VestingParams {
+        beneficiary: Address::generate(&env),
+        total: 1,
+        start: 0,
+        cliff: 0,
+        duration: 1,
+        revocable: false,
+    });
+    assert_eq!(res, Err(Ok(contract_error(Error::InvalidAmount))));
+
+    // the deposit can still be refunded
+    env.ledger().set_timestamp(UNLOCK_PERIOD as u64 * 3);
+    client.retract_ballot(&ballot_id);
+    let report = client.solvency_report();
+    assert_eq!(report.dao_balance, 0);
+    assert_eq!(report.surplus, 0);
+}
@@ -0,0 +1,12 @@
+use soroban_sdk::{contracttype, Address, Symbol};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+
+/// Quoted asset, compatible with the Reflector oracle asset format
+pub enum Asset {
+    /// Stellar asset contract address (SAC address for classic assets)
+    Stellar(Address),
+    /// Code of an asset from an external source
+    Other(Symbol),
+}
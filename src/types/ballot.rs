@@ -1,6 +1,9 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, String, Vec};
 
-use super::{ballot_status::BallotStatus, ballot_category::BallotCategory};
+use super::{
+    ballot_category::BallotCategory, ballot_payload::BallotPayload, ballot_status::BallotStatus,
+    content_hash::ContentHash, execution_status::ExecutionStatus, vote_rationale::VoteRationale,
+};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -13,12 +16,32 @@ pub struct Ballot {
     pub category: BallotCategory,
     /// Short title
     pub title: String,
+    /// Structured data matching the ballot type
+    pub payload: BallotPayload,
     /// Description text or URL
     pub description: String,
+    /// SHA-256 hash of the proposal document
+    pub content_hash: ContentHash,
+    /// URI of the proposal document
+    pub uri: Option<String>,
     /// Current status
     pub status: BallotStatus,
     /// Deposited DAO tokens amount
     pub deposit: i128,
     /// Creation timestamp
     pub created: u64,
+    /// Number of votes in favor recorded on finalization
+    pub votes_for: u32,
+    /// Number of votes against recorded on finalization
+    pub votes_against: u32,
+    /// Explanation of the voting decision
+    pub rationale: VoteRationale,
+    /// Options to choose from, empty for binary ballots
+    pub options: Vec<String>,
+    /// Number of votes for each option recorded on finalization
+    pub option_votes: Vec<u32>,
+    /// Index of the winning option, if any
+    pub outcome: Option<u32>,
+    /// Result of the contract invocation for GenericCall ballots
+    pub execution: ExecutionStatus,
 }
@@ -7,7 +7,10 @@ pub enum BallotCategory {
     AddNode = 0,
     AddPriceFeed = 1,
     AddAsset = 2,
-    General = 3
+    General = 3,
+    RemoveNode = 4,
+    RemoveAsset = 5,
+    GenericCall = 6
 }
 
 impl BallotCategory {
@@ -17,6 +20,9 @@ impl BallotCategory {
             BallotCategory::AddPriceFeed,
             BallotCategory::AddAsset,
             BallotCategory::General,
+            BallotCategory::RemoveNode,
+            BallotCategory::RemoveAsset,
+            BallotCategory::GenericCall,
         ]
         .iter()
         .copied()
@@ -1,6 +1,6 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, String, Vec};
 
-use super::ballot_category::BallotCategory;
+use super::{ballot_category::BallotCategory, ballot_payload::BallotPayload, content_hash::ContentHash};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -13,6 +13,14 @@ pub struct BallotInitParams {
     pub category: BallotCategory,
     /// Short title
     pub title: String,
+    /// Structured data matching the ballot type
+    pub payload: BallotPayload,
     /// Description text or URL
     pub description: String,
+    /// SHA-256 hash of the proposal document
+    pub content_hash: ContentHash,
+    /// URI of the proposal document
+    pub uri: Option<String>,
+    /// Options to choose from, empty for binary ballots
+    pub options: Vec<String>,
 }
@@ -0,0 +1,26 @@
+use soroban_sdk::{contracttype, BytesN};
+
+use super::{
+    asset::Asset, contract_call::ContractCall, node_params::NodeParams, price_feed_params::PriceFeedParams,
+};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+
+/// Structured ballot data, specific for each ballot category
+pub enum BallotPayload {
+    /// General ballots carry no structured data
+    None,
+    /// Node to add to the cluster
+    AddNode(NodeParams),
+    /// Price feed to add
+    AddPriceFeed(PriceFeedParams),
+    /// Asset to add to the quoted assets list
+    AddAsset(Asset),
+    /// Public key of the node to remove from the cluster
+    RemoveNode(BytesN<32>),
+    /// Asset to remove from the quoted assets list
+    RemoveAsset(Asset),
+    /// Contract invocation to execute
+    GenericCall(ContractCall),
+}
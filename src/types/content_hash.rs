@@ -0,0 +1,14 @@
+use soroban_sdk::{contracttype, BytesN};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+
+/// Optional SHA-256 hash of the proposal document
+/// Stands in for `Option<BytesN<32>>`, which can't be a `contracttype` field in soroban-sdk 22 since the derived
+/// XDR conversion requires `ScVal: From<BytesN<32>>`; replace with `Option` once the SDK is upgraded
+pub enum ContentHash {
+    /// No proposal document hash provided
+    None,
+    /// SHA-256 hash of the proposal document
+    Sha256(BytesN<32>),
+}
@@ -0,0 +1,14 @@
+use soroban_sdk::{contracttype, Address, Symbol, Val, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+
+/// Contract invocation executed by the DAO contract once the ballot is accepted
+pub struct ContractCall {
+    /// Address of the invoked contract
+    pub contract: Address,
+    /// Name of the invoked function
+    pub function: Symbol,
+    /// Invocation arguments
+    pub args: Vec<Val>,
+}
@@ -1,6 +1,9 @@
 use soroban_sdk::{contracttype, Address, Map};
 
-use super::ballot_category::BallotCategory;
+use super::{
+    ballot_category::BallotCategory, emission_schedule::EmissionSchedule, revenue_shares::RevenueShares,
+    voting_rules::VotingRules,
+};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -15,6 +18,14 @@ pub struct ContractConfig {
     pub amount: i128,
     /// Initial deposit amounts for each ballot category
     pub deposit_params: Map<BallotCategory, i128>,
+    /// Initial quorum and approval threshold for each ballot category
+    pub voting_rules: Map<BallotCategory, VotingRules>,
+    /// Emission model used to calculate unlocked amounts
+    pub emission_schedule: EmissionSchedule,
+    /// Shares of the revenue pool distributed on unlock
+    pub revenue_shares: RevenueShares,
     /// DAO start date
-    pub start_date: u64
+    pub start_date: u64,
+    /// Reflector oracle contract address, governed by the DAO
+    pub oracle: Option<Address>,
 }
@@ -0,0 +1,16 @@
+use soroban_sdk::contracttype;
+
+use super::halving_params::HalvingParams;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+
+/// Model used to calculate the amount of DAO tokens emitted on every unlock
+pub enum EmissionSchedule {
+    /// Percentage of the remaining DAO balance, 10000 is 100%
+    Decay(u32),
+    /// Fixed amount per unlock period
+    Fixed(i128),
+    /// Fixed amount per unlock period, halved at configured epochs
+    Halving(HalvingParams),
+}
@@ -1,7 +1,7 @@
 use soroban_sdk::contracterror;
 
 #[contracterror]
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 /// Contract-specific error codes
 pub enum Error {
     /// The contract is not initialized.
@@ -18,12 +18,56 @@ pub enum Error {
     Overflow = 5,
     /// Operators param is invalid
     InvalidOperators = 6,
+    /// Quorum or approval threshold is invalid
+    InvalidVotingRules = 7,
+    /// Revenue shares are invalid
+    InvalidRevenueShares = 8,
+    /// Emission schedule parameters are invalid
+    InvalidEmissionSchedule = 9,
     /// Last unlock process has been executed less than a week ago
     UnlockUnavailable = 10,
     /// Proposal has been created less than two weeks ago and refund is not available yet, or the ballot has been closed
     RefundUnavailable = 11,
+    /// Emission preview is not available for past epochs
+    InvalidEpoch = 12,
+    /// Developer recipients have not been set or their splits don't sum up to 100%
+    InvalidDevelopers = 13,
     /// Ballot with such ID has not been registered or expired
     BallotNotFound = 20,
     /// Ballot voting has ended and it cannot be modified
     BallotClosed = 21,
+    /// Number of participating votes is lower than the quorum required for the ballot category
+    QuorumNotReached = 22,
+    /// Vote rationale text is empty or too long
+    InvalidRationale = 23,
+    /// Voting results don't match the ballot options
+    InvalidVotes = 24,
+    /// Price feed with such ID has not been registered
+    FeedNotFound = 30,
+    /// Vesting schedule parameters are invalid
+    InvalidVestingParams = 40,
+    /// Beneficiary already has a vesting schedule
+    VestingExists = 41,
+    /// Vesting schedule for the beneficiary has not been found
+    VestingNotFound = 42,
+    /// Vesting schedule cannot be revoked
+    VestingIrrevocable = 43,
+    /// Payout address for the claimant has not been set
+    PayoutNotSet = 50,
+    /// Claim operator for the claimant has not been set
+    ClaimOperatorNotSet = 51,
+    /// Account is not one of the configured unlock recipients
+    InvalidRecipient = 52,
+    /// Function group has been paused by the guardian
+    Paused = 60,
+    /// Signer set is empty, contains duplicates, or the threshold is out of range
+    InvalidSigners = 70,
+    /// Signer has not approved the action
+    ApprovalNotFound = 71,
+    /// Queued parameter change with such ID has not been found
+    ChangeNotFound = 80,
+    /// Timelock delay of the queued parameter change has not elapsed yet
+    TimelockActive = 81,
+    /// Timelock delay exceeds the maximum allowed delay
+    InvalidTimelockDelay = 82,
 }
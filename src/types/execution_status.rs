@@ -0,0 +1,12 @@
+use soroban_sdk::contracttype;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, Copy)]
+pub enum ExecutionStatus {
+    /// Ballot doesn't require execution or hasn't been accepted yet
+    None = 0,
+    /// Contract invocation has been executed successfully
+    Succeeded = 1,
+    /// Contract invocation has failed
+    Failed = 2
+}
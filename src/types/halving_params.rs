@@ -0,0 +1,12 @@
+use soroban_sdk::{contracttype, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+
+/// Stepwise halving emission parameters
+pub struct HalvingParams {
+    /// Amount of DAO tokens emitted per unlock period before the first halving
+    pub amount: i128,
+    /// Unlock epochs when the emitted amount is halved, in ascending order
+    pub epochs: Vec<u32>,
+}
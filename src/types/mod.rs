@@ -3,4 +3,26 @@ pub mod contract_config;
 pub mod ballot_init_params;
 pub mod ballot_status;
 pub mod ballot_category;
-pub mod ballot;
\ No newline at end of file
+pub mod ballot;
+pub mod voting_rules;
+pub mod vote_rationale;
+pub mod asset;
+pub mod node_params;
+pub mod price_feed_params;
+pub mod ballot_payload;
+pub mod node_info;
+pub mod price_feed;
+pub mod contract_call;
+pub mod execution_status;
+pub mod revenue_shares;
+pub mod solvency_report;
+pub mod vesting_params;
+pub mod vesting_schedule;
+pub mod halving_params;
+pub mod emission_schedule;
+pub mod pause_group;
+pub mod role;
+pub mod signer_set;
+pub mod param_change;
+pub mod queued_change;
+pub mod content_hash;
\ No newline at end of file
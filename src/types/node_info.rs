@@ -0,0 +1,14 @@
+use soroban_sdk::{contracttype, BytesN, String};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+
+/// Reflector node approved by the DAO
+pub struct NodeInfo {
+    /// Node public key
+    pub pubkey: BytesN<32>,
+    /// Node endpoint URL
+    pub endpoint: String,
+    /// Timestamp of the node approval
+    pub joined: u64,
+}
@@ -0,0 +1,12 @@
+use soroban_sdk::{contracttype, BytesN, String};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+
+/// Reflector node proposed for joining the cluster
+pub struct NodeParams {
+    /// Node public key
+    pub pubkey: BytesN<32>,
+    /// Node endpoint URL
+    pub endpoint: String,
+}
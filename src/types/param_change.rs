@@ -0,0 +1,35 @@
+use soroban_sdk::{contracttype, Address, Map, Vec};
+
+use super::{
+    ballot_category::BallotCategory, emission_schedule::EmissionSchedule, revenue_shares::RevenueShares, role::Role,
+    voting_rules::VotingRules,
+};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+
+/// Contract parameter change applied through the timelock queue
+pub enum ParamChange {
+    /// Deposit amounts for each ballot category
+    Deposit(Map<BallotCategory, i128>),
+    /// Voting rules for each ballot category
+    VotingRules(Map<BallotCategory, VotingRules>),
+    /// Emission model
+    EmissionSchedule(EmissionSchedule),
+    /// Revenue shares
+    RevenueShares(RevenueShares),
+    /// Operators' account addresses receiving the unlocked tokens
+    Operators(Vec<Address>),
+    /// Developer account addresses and their splits
+    Developers(Map<Address, u32>),
+    /// Keeper reward amount
+    KeeperReward(i128),
+    /// Reflector oracle contract address
+    Oracle(Address),
+    /// Role assignment
+    SetRole(Role, Address),
+    /// Role revocation
+    RevokeRole(Role),
+    /// Timelock delay in seconds
+    TimelockDelay(u64),
+}
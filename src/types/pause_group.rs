@@ -0,0 +1,16 @@
+use soroban_sdk::contracttype;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, Copy)]
+
+/// Group of contract functions that can be paused by the guardian
+pub enum PauseGroup {
+    /// Ballot creation and retraction
+    Ballots = 0,
+    /// Recording voting results
+    Voting = 1,
+    /// Unlocking DAO tokens
+    Unlock = 2,
+    /// Claiming unlocked tokens and creating vesting schedules
+    Claims = 3
+}
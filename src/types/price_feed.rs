@@ -0,0 +1,24 @@
+use soroban_sdk::{contracttype, Address};
+
+use super::asset::Asset;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+
+/// Price feed sanctioned by the DAO
+pub struct PriceFeed {
+    /// Unique feed ID, matches the ID of the ballot that added the feed
+    pub id: u64,
+    /// Base asset of the price feed
+    pub base: Asset,
+    /// Quote asset of the price feed
+    pub quote: Asset,
+    /// Price update resolution in seconds
+    pub resolution: u32,
+    /// Number of decimal places in quoted prices
+    pub decimals: u32,
+    /// Address of the oracle contract serving the feed
+    pub oracle: Address,
+    /// Whether the feed is currently active
+    pub active: bool,
+}
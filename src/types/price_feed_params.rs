@@ -0,0 +1,20 @@
+use soroban_sdk::{contracttype, Address};
+
+use super::asset::Asset;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+
+/// Price feed proposed for addition
+pub struct PriceFeedParams {
+    /// Base asset of the price feed
+    pub base: Asset,
+    /// Quote asset of the price feed
+    pub quote: Asset,
+    /// Price update resolution in seconds
+    pub resolution: u32,
+    /// Number of decimal places in quoted prices
+    pub decimals: u32,
+    /// Address of the oracle contract serving the feed
+    pub oracle: Address,
+}
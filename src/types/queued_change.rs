@@ -0,0 +1,16 @@
+use soroban_sdk::contracttype;
+
+use super::param_change::ParamChange;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+
+/// Parameter change waiting in the timelock queue
+pub struct QueuedChange {
+    /// Unique change ID
+    pub id: u64,
+    /// Parameter change
+    pub change: ParamChange,
+    /// Timestamp after which the change can be executed
+    pub eta: u64,
+}
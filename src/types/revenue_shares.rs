@@ -0,0 +1,12 @@
+use soroban_sdk::contracttype;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, Copy)]
+
+/// Shares of the revenue accrued since the last unlock distributed on unlock, 10000 is 100%
+pub struct RevenueShares {
+    /// Share distributed among operators
+    pub operators: u32,
+    /// Share distributed to the developer organization
+    pub developers: u32,
+}
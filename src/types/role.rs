@@ -0,0 +1,16 @@
+use soroban_sdk::contracttype;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, Copy)]
+
+/// Operational role that can be assigned to a separate account, unassigned roles fall back to the admin or the signer set
+pub enum Role {
+    /// Sets contract parameters and unlock recipients
+    Governor = 0,
+    /// Records ballot voting results
+    VoteRecorder = 1,
+    /// Pauses contract functions in case of emergency
+    Guardian = 2,
+    /// Manages treasury surplus and vesting schedules
+    Treasurer = 3
+}
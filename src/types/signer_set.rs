@@ -0,0 +1,12 @@
+use soroban_sdk::{contracttype, Address, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+
+/// Set of signers authorizing admin actions
+pub struct SignerSet {
+    /// Signer account addresses
+    pub signers: Vec<Address>,
+    /// Number of signer approvals required to execute an admin action
+    pub threshold: u32,
+}
@@ -0,0 +1,22 @@
+use soroban_sdk::contracttype;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+
+/// Reconciliation of internal DAO accounting with the actual token balance
+pub struct SolvencyReport {
+    /// DAO tokens held by the contract
+    pub token_balance: i128,
+    /// Internal DAO treasury balance, including escrowed deposits
+    pub dao_balance: i128,
+    /// Total outstanding balances available for claiming
+    pub liabilities: i128,
+    /// Refundable deposits of open ballots
+    pub escrowed: i128,
+    /// Tokens reserved for vesting schedules and not released yet
+    pub vesting: i128,
+    /// Revenue pool balance
+    pub revenue_balance: i128,
+    /// Tokens not accounted by the DAO, negative value indicates a deficit
+    pub surplus: i128,
+}
@@ -0,0 +1,20 @@
+use soroban_sdk::contracttype;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+
+/// Cliff-and-linear vesting schedule of a beneficiary
+pub struct VestingSchedule {
+    /// Total amount of DAO tokens to vest
+    pub total: i128,
+    /// Vesting start timestamp
+    pub start: u64,
+    /// Cliff duration in seconds, nothing is vested before the cliff ends
+    pub cliff: u64,
+    /// Vesting duration in seconds, including the cliff
+    pub duration: u64,
+    /// Whether the schedule can be revoked
+    pub revocable: bool,
+    /// Vested amount already released to the available balance
+    pub released: i128,
+}
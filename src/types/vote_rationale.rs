@@ -0,0 +1,14 @@
+use soroban_sdk::{contracttype, BytesN, String};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+
+/// Explanation of the voting decision attached to a ballot
+pub enum VoteRationale {
+    /// No explanation provided
+    None,
+    /// Short explanation text
+    Text(String),
+    /// SHA-256 hash of an off-chain document with the explanation
+    Hash(BytesN<32>),
+}
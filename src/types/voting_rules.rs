@@ -0,0 +1,12 @@
+use soroban_sdk::contracttype;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, Copy)]
+
+/// Voting requirements applied to ballots of a particular category
+pub struct VotingRules {
+    /// Minimum number of participating votes required to finalize a ballot
+    pub quorum: u32,
+    /// Share of votes in favor that must be exceeded to accept a ballot, 10000 is 100%
+    pub threshold: u32,
+}